@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::Path;
+use tauri::command;
+use chrono::{Datelike, Local};
+use crate::database::models::{
+    AttendanceMapping, OfficeHours, Holiday, AttendanceImportResult, DailyAttendance, AttendanceSummary
+};
+use crate::database::attendance::{
+    save_punches_in_db, get_unmapped_device_users_from_db, get_attendance_mappings_from_db,
+    set_attendance_mapping_in_db, delete_attendance_mapping_from_db, get_office_hours_from_db,
+    update_office_hours_in_db, get_holidays_from_db, add_holiday_in_db, delete_holiday_from_db,
+    get_daily_attendance_from_db, get_attendance_summary_from_db
+};
+use crate::utils::attendance::{parse_punch_log, parse_time};
+use crate::utils::date_utils::{parse_date, format_date};
+
+fn validate_month(month: u32) -> Result<(), String> {
+    if (1..=12).contains(&month) {
+        Ok(())
+    } else {
+        Err(format!("Invalid month: {}", month))
+    }
+}
+
+/// Imports a punch log exported from the fingerprint terminal. Punches already
+/// imported are skipped, so overlapping exports can be loaded safely.
+#[command]
+pub fn import_attendance(file_path: String) -> Result<AttendanceImportResult, String> {
+    let bytes = fs::read(&file_path).map_err(|e| format!("Failed to read attendance file: {}", e))?;
+    let log = parse_punch_log(&String::from_utf8_lossy(&bytes));
+
+    if log.punches.is_empty() {
+        return Err("No attendance records found in the file".to_string());
+    }
+
+    let source_file = Path::new(&file_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or(file_path.clone());
+
+    let (imported, duplicates) = save_punches_in_db(&log.punches, &source_file)
+        .map_err(|e| format!("Failed to import attendance: {}", e))?;
+
+    let unmapped_user_ids = get_unmapped_device_users_from_db()
+        .map_err(|e| format!("Failed to import attendance: {}", e))?;
+
+    Ok(AttendanceImportResult {
+        imported,
+        duplicates,
+        invalid_lines: log.invalid_lines,
+        unmapped_user_ids,
+    })
+}
+
+#[command]
+pub fn get_attendance_mappings() -> Result<Vec<AttendanceMapping>, String> {
+    match get_attendance_mappings_from_db() {
+        Ok(mappings) => Ok(mappings),
+        Err(e) => Err(format!("Failed to get attendance mappings: {}", e)),
+    }
+}
+
+#[command]
+pub fn set_attendance_mapping(device_user_id: String, staff_id: i32) -> Result<String, String> {
+    let device_user_id = device_user_id.trim();
+    if device_user_id.is_empty() {
+        return Err("Device user ID is required".to_string());
+    }
+
+    match set_attendance_mapping_in_db(device_user_id, staff_id) {
+        Ok(_) => Ok("Attendance mapping saved".to_string()),
+        Err(e) => Err(format!("Failed to save attendance mapping: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_attendance_mapping(device_user_id: String) -> Result<String, String> {
+    match delete_attendance_mapping_from_db(device_user_id.trim()) {
+        Ok(_) => Ok("Attendance mapping deleted".to_string()),
+        Err(e) => Err(format!("Failed to delete attendance mapping: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_office_hours() -> Result<OfficeHours, String> {
+    match get_office_hours_from_db() {
+        Ok(hours) => Ok(hours),
+        Err(e) => Err(format!("Failed to get office hours: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_office_hours(mut hours: OfficeHours) -> Result<String, String> {
+    let start = parse_time(&hours.start_time)
+        .ok_or_else(|| format!("Invalid start time: {}", hours.start_time))?;
+    let end = parse_time(&hours.end_time)
+        .ok_or_else(|| format!("Invalid end time: {}", hours.end_time))?;
+
+    if end <= start {
+        return Err("Office hours must end after they start".to_string());
+    }
+
+    if hours.grace_minutes < 0 {
+        return Err("Grace period cannot be negative".to_string());
+    }
+
+    hours.start_time = start.format("%H:%M").to_string();
+    hours.end_time = end.format("%H:%M").to_string();
+
+    match update_office_hours_in_db(&hours) {
+        Ok(_) => Ok("Office hours updated".to_string()),
+        Err(e) => Err(format!("Failed to update office hours: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_holidays(year: Option<i32>) -> Result<Vec<Holiday>, String> {
+    match get_holidays_from_db(year) {
+        Ok(holidays) => Ok(holidays),
+        Err(e) => Err(format!("Failed to get holidays: {}", e)),
+    }
+}
+
+/// Adds a public, bank or Poya holiday. Attendance does not expect anyone
+/// in the office on a holiday.
+#[command]
+pub fn add_holiday(mut holiday: Holiday) -> Result<i64, String> {
+    let date = parse_date(&holiday.holiday_date)
+        .ok_or_else(|| format!("Invalid holiday date: {}", holiday.holiday_date))?;
+    holiday.holiday_date = format_date(date);
+
+    holiday.name = holiday.name.trim().to_string();
+    if holiday.name.is_empty() {
+        return Err("Holiday name is required".to_string());
+    }
+
+    let existing = get_holidays_from_db(Some(date.year()))
+        .map_err(|e| format!("Failed to get holidays: {}", e))?;
+    if let Some(existing) = existing.iter().find(|other| other.holiday_date == holiday.holiday_date) {
+        return Err(format!("{} is already a holiday: {}", existing.holiday_date, existing.name));
+    }
+
+    match add_holiday_in_db(&holiday) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add holiday: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_holiday(id: i32) -> Result<String, String> {
+    match delete_holiday_from_db(id) {
+        Ok(0) => Err(format!("Holiday {} not found", id)),
+        Ok(_) => Ok("Holiday deleted".to_string()),
+        Err(e) => Err(format!("Failed to delete holiday: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_daily_attendance(staff_id: i32, month: u32, year: Option<i32>) -> Result<Vec<DailyAttendance>, String> {
+    validate_month(month)?;
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match get_daily_attendance_from_db(staff_id, year, month) {
+        Ok(days) => Ok(days),
+        Err(e) => Err(format!("Failed to get attendance: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_attendance_summary(month: u32, year: Option<i32>) -> Result<Vec<AttendanceSummary>, String> {
+    validate_month(month)?;
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match get_attendance_summary_from_db(year, month) {
+        Ok(summary) => Ok(summary),
+        Err(e) => Err(format!("Failed to get attendance summary: {}", e)),
+    }
+}
\ No newline at end of file
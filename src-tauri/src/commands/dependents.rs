@@ -0,0 +1,82 @@
+use tauri::command;
+use crate::database::models::Dependent;
+use crate::database::dependents::{
+    RELATIONSHIPS, get_dependents_from_db, insert_dependent_in_db, update_dependent_in_db,
+    delete_dependent_from_db
+};
+use crate::utils::date_utils::{parse_date, format_date};
+use crate::utils::nic_converter::convert_old_to_new_nic;
+
+/// Validates a dependent and normalizes its relationship, date of birth and NIC
+fn prepare_dependent(dependent: &mut Dependent) -> Result<(), String> {
+    dependent.relationship = dependent.relationship.trim().to_lowercase();
+    if !RELATIONSHIPS.contains(&dependent.relationship.as_str()) {
+        return Err(format!("Invalid relationship: {}", dependent.relationship));
+    }
+
+    dependent.full_name = dependent.full_name.trim().to_string();
+    if dependent.full_name.is_empty() {
+        return Err("Dependent name is required".to_string());
+    }
+
+    dependent.date_of_birth = match dependent.date_of_birth.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => Some(format_date(
+            parse_date(date).ok_or_else(|| format!("Invalid date of birth: {}", date))?,
+        )),
+        _ => None,
+    };
+
+    // Children under 16 have no NIC
+    dependent.nic_number = match dependent.nic_number.as_deref().map(str::trim) {
+        Some(nic) if !nic.is_empty() => Some(
+            convert_old_to_new_nic(nic).map_err(|e| format!("{}: {}", e, nic))?,
+        ),
+        _ => None,
+    };
+
+    if dependent.wop_nominee && !matches!(dependent.relationship.as_str(), "spouse" | "son" | "daughter") {
+        return Err("Only a spouse or child can be a W&OP nominee".to_string());
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn get_dependents(staff_id: i32) -> Result<Vec<Dependent>, String> {
+    match get_dependents_from_db(staff_id) {
+        Ok(dependents) => Ok(dependents),
+        Err(e) => Err(format!("Failed to get dependents: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_dependent(mut dependent: Dependent) -> Result<i64, String> {
+    prepare_dependent(&mut dependent)?;
+
+    match insert_dependent_in_db(&dependent) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add dependent: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_dependent(mut dependent: Dependent) -> Result<String, String> {
+    if dependent.id.is_none() {
+        return Err("Dependent ID is required for update".to_string());
+    }
+
+    prepare_dependent(&mut dependent)?;
+
+    match update_dependent_in_db(&dependent) {
+        Ok(_) => Ok("Dependent updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update dependent: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_dependent(id: i32) -> Result<String, String> {
+    match delete_dependent_from_db(id) {
+        Ok(_) => Ok("Dependent deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete dependent: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use tauri::command;
+use crate::database::models::{Designation, VacancyReport, Staff};
+use crate::database::designations::{
+    find_designation_from_db, get_designations_from_db, insert_designation_in_db,
+    update_designation_in_db, delete_designation_from_db, get_vacancy_report_from_db,
+    set_office_cadre_in_db
+};
+
+/// Links a staff record to its designation, replacing the free-text value
+/// with the canonical name
+pub fn resolve_designation(staff: &mut Staff) -> Result<(), String> {
+    let designation = find_designation_from_db(&staff.designation)
+        .map_err(|e| format!("Failed to get designation: {}", e))?
+        .ok_or_else(|| format!("Unknown designation: {}", staff.designation))?;
+
+    staff.designation_id = designation.id;
+    staff.designation = designation.name;
+    Ok(())
+}
+
+fn validate_designation(designation: &Designation) -> Result<(), String> {
+    if designation.name.trim().is_empty() {
+        return Err("Designation name is required".to_string());
+    }
+
+    if designation.approved_cadre < 0 {
+        return Err("Approved cadre cannot be negative".to_string());
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn get_designations() -> Result<Vec<Designation>, String> {
+    match get_designations_from_db() {
+        Ok(designations) => Ok(designations),
+        Err(e) => Err(format!("Failed to get designations: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_designation(designation: Designation) -> Result<String, String> {
+    validate_designation(&designation)?;
+    match insert_designation_in_db(&designation) {
+        Ok(id) => Ok(format!("Designation added successfully with ID: {}", id)),
+        Err(e) => Err(format!("Failed to add designation: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_designation(designation: Designation) -> Result<String, String> {
+    validate_designation(&designation)?;
+    match update_designation_in_db(&designation) {
+        Ok(_) => Ok("Designation updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update designation: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_designation(id: i32) -> Result<String, String> {
+    match delete_designation_from_db(id) {
+        Ok(_) => Ok("Designation deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete designation: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_vacancy_report(office_id: Option<i32>) -> Result<VacancyReport, String> {
+    match get_vacancy_report_from_db(office_id) {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to get vacancy report: {}", e)),
+    }
+}
+
+#[command]
+pub fn set_office_cadre(office_id: i32, designation_id: i32, approved_cadre: i32) -> Result<String, String> {
+    if approved_cadre < 0 {
+        return Err("Approved cadre cannot be negative".to_string());
+    }
+
+    match set_office_cadre_in_db(office_id, designation_id, approved_cadre) {
+        Ok(_) => Ok("Office cadre updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update office cadre: {}", e)),
+    }
+}
\ No newline at end of file
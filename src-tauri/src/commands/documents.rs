@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle};
+use crate::database::models::StaffDocument;
+use crate::database::documents::{
+    DOCUMENT_TYPES, get_documents_from_db, get_document_from_db, find_document_by_hash_from_db,
+    insert_document_in_db, delete_document_from_db
+};
+use crate::utils::date_utils::{parse_date, format_date};
+use crate::utils::documents::{content_hash, open_with_default_app, temp_file_name};
+
+/// Directory stored documents are kept in, created on first use
+fn documents_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or("Could not find the application data directory")?
+        .join("documents");
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Could not create document store: {}", e))?;
+    Ok(dir)
+}
+
+fn load_document(id: i32) -> Result<StaffDocument, String> {
+    get_document_from_db(id)
+        .map_err(|e| format!("Failed to get document: {}", e))?
+        .ok_or_else(|| format!("Document {} not found", id))
+}
+
+/// Reads a stored document, checking it still matches the hash it was stored under
+fn read_verified(app_handle: &AppHandle, document: &StaffDocument) -> Result<Vec<u8>, String> {
+    let hash = document.content_hash.clone().unwrap_or_default();
+    let bytes = fs::read(documents_dir(app_handle)?.join(&hash))
+        .map_err(|e| format!("Document file for '{}' is missing: {}", document.title, e))?;
+
+    if content_hash(&bytes) != hash {
+        return Err(format!("Document file for '{}' is corrupt: contents do not match its hash", document.title));
+    }
+
+    Ok(bytes)
+}
+
+#[command]
+pub fn attach_document(app_handle: AppHandle, source_path: String, mut document: StaffDocument) -> Result<i64, String> {
+    if !DOCUMENT_TYPES.contains(&document.document_type.as_str()) {
+        return Err(format!("Invalid document type: {}", document.document_type));
+    }
+
+    if document.title.trim().is_empty() {
+        return Err("Document title is required".to_string());
+    }
+
+    document.document_date = match document.document_date.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => Some(format_date(
+            parse_date(date).ok_or_else(|| format!("Invalid document date: {}", date))?,
+        )),
+        _ => None,
+    };
+
+    document.expiry_date = match document.expiry_date.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => Some(format_date(
+            parse_date(date).ok_or_else(|| format!("Invalid expiry date: {}", date))?,
+        )),
+        _ => None,
+    };
+
+    let bytes = fs::read(&source_path).map_err(|e| format!("Failed to read document: {}", e))?;
+    let hash = content_hash(&bytes);
+
+    let existing = find_document_by_hash_from_db(document.staff_id, &hash)
+        .map_err(|e| format!("Failed to attach document: {}", e))?;
+    if let Some(existing) = existing {
+        return Err(format!("This file is already attached as '{}'", existing.title));
+    }
+
+    // Identical content is stored once, however many records refer to it.
+    // A stored copy that no longer matches its hash is replaced.
+    let stored_path = documents_dir(&app_handle)?.join(&hash);
+    let intact = fs::read(&stored_path).is_ok_and(|stored| content_hash(&stored) == hash);
+    if !intact {
+        fs::write(&stored_path, &bytes).map_err(|e| format!("Failed to store document: {}", e))?;
+    }
+
+    document.original_file_name = Some(
+        Path::new(&source_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or(hash.clone()),
+    );
+    document.content_hash = Some(hash);
+    document.file_size = Some(bytes.len() as i64);
+
+    match insert_document_in_db(&document) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to attach document: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_staff_documents(staff_id: i32) -> Result<Vec<StaffDocument>, String> {
+    match get_documents_from_db(Some(staff_id)) {
+        Ok(documents) => Ok(documents),
+        Err(e) => Err(format!("Failed to get documents: {}", e)),
+    }
+}
+
+/// Opens a document in the default application. A copy is written to the
+/// temporary directory, named by its hash with the original extension so the
+/// right program is used.
+#[command]
+pub fn open_document(app_handle: AppHandle, id: i32) -> Result<String, String> {
+    let document = load_document(id)?;
+    let bytes = read_verified(&app_handle, &document)?;
+
+    let dir = std::env::temp_dir().join("forest-office-documents");
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to open document: {}", e))?;
+
+    let hash = document.content_hash.clone().unwrap_or_default();
+    let path = dir.join(temp_file_name(&hash, document.original_file_name.as_deref()));
+    fs::write(&path, bytes).map_err(|e| format!("Failed to open document: {}", e))?;
+
+    match open_with_default_app(&path) {
+        Ok(_) => Ok(format!("Opened {}", document.title)),
+        Err(e) => Err(format!("Failed to open document: {}", e)),
+    }
+}
+
+#[command]
+pub fn export_document(app_handle: AppHandle, id: i32, output_path: String) -> Result<String, String> {
+    let document = load_document(id)?;
+    let bytes = read_verified(&app_handle, &document)?;
+
+    match fs::write(&output_path, bytes) {
+        Ok(_) => Ok(format!("Document exported to {}", output_path)),
+        Err(e) => Err(format!("Failed to export document: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_document(app_handle: AppHandle, id: i32) -> Result<String, String> {
+    let document = load_document(id)?;
+    let hash = document.content_hash.unwrap_or_default();
+
+    let shared = delete_document_from_db(id, &hash)
+        .map_err(|e| format!("Failed to delete document: {}", e))?;
+
+    if !shared {
+        let path = documents_dir(&app_handle)?.join(&hash);
+        if let Err(e) = fs::remove_file(path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(format!("Document record deleted but the file could not be removed: {}", e));
+            }
+        }
+    }
+
+    Ok("Document deleted successfully".to_string())
+}
+
+/// Checks stored files against their hashes, returning the documents whose
+/// file is missing or has changed
+#[command]
+pub fn verify_documents(app_handle: AppHandle, staff_id: Option<i32>) -> Result<Vec<StaffDocument>, String> {
+    let documents = get_documents_from_db(staff_id)
+        .map_err(|e| format!("Failed to get documents: {}", e))?;
+
+    Ok(documents.into_iter()
+        .filter(|document| read_verified(&app_handle, document).is_err())
+        .collect())
+}
\ No newline at end of file
@@ -0,0 +1,106 @@
+use tauri::command;
+use crate::database::models::{DuplicateStaffGroup, StaffMerge};
+use crate::database::duplicates::{find_duplicate_staff_from_db, merge_staff_in_db, get_staff_merges_from_db};
+use crate::database::operations::get_staff_by_id_from_db;
+use crate::database::postings::get_postings_from_db;
+use crate::database::leave::get_leave_applications_from_db;
+use crate::commands::postings::posting_period;
+use crate::commands::leave::leave_period;
+use crate::utils::date_utils::periods_overlap;
+
+/// Postings and leave of the merged record that overlap a different entry on
+/// the kept record. The same entry recorded on both is not a conflict; the
+/// merge keeps one copy.
+fn merge_conflicts(keep_id: i32, merge_id: i32) -> Result<Vec<String>, String> {
+    let mut conflicts = Vec::new();
+
+    let postings = |id| get_postings_from_db(id).map_err(|e| format!("Failed to get postings: {}", e));
+    let kept = postings(keep_id)?;
+    for posting in postings(merge_id)? {
+        if kept.iter().any(|other| {
+            other.station.eq_ignore_ascii_case(&posting.station)
+                && other.from_date == posting.from_date
+                && other.to_date == posting.to_date
+        }) {
+            continue;
+        }
+        let Ok((from, to)) = posting_period(&posting) else { continue };
+        for other in &kept {
+            let Ok((other_from, other_to)) = posting_period(other) else { continue };
+            if periods_overlap(from, to, other_from, other_to) {
+                conflicts.push(format!(
+                    "Posting at {} from {} overlaps the posting at {} from {}",
+                    posting.station, posting.from_date, other.station, other.from_date
+                ));
+            }
+        }
+    }
+
+    let applications = |id| get_leave_applications_from_db(id)
+        .map_err(|e| format!("Failed to get leave applications: {}", e));
+    let kept: Vec<_> = applications(keep_id)?.into_iter().filter(|other| other.status != "rejected").collect();
+    for application in applications(merge_id)?.into_iter().filter(|application| application.status != "rejected") {
+        if kept.iter().any(|other| {
+            other.leave_type_id == application.leave_type_id
+                && other.from_date == application.from_date
+                && other.to_date == application.to_date
+        }) {
+            continue;
+        }
+        let Ok((from, to)) = leave_period(&application) else { continue };
+        for other in &kept {
+            let Ok((other_from, other_to)) = leave_period(other) else { continue };
+            if periods_overlap(from, Some(to), other_from, Some(other_to)) {
+                conflicts.push(format!(
+                    "Leave from {} to {} overlaps the leave from {} to {}",
+                    application.from_date, application.to_date, other.from_date, other.to_date
+                ));
+            }
+        }
+    }
+
+    Ok(conflicts)
+}
+
+/// Groups of staff records that appear to be the same person
+#[command]
+pub fn find_duplicate_staff() -> Result<Vec<DuplicateStaffGroup>, String> {
+    match find_duplicate_staff_from_db() {
+        Ok(groups) => Ok(groups),
+        Err(e) => Err(format!("Failed to find duplicate staff: {}", e)),
+    }
+}
+
+/// Folds the `merge_id` record, with its sub-records and photo, into
+/// `keep_id` and deletes it. Refused while the two records have postings or
+/// leave that overlap, so they can be corrected first.
+#[command]
+pub fn merge_staff(keep_id: i32, merge_id: i32, merged_by: Option<String>) -> Result<StaffMerge, String> {
+    if keep_id == merge_id {
+        return Err("A staff record cannot be merged into itself".to_string());
+    }
+
+    for id in [keep_id, merge_id] {
+        get_staff_by_id_from_db(id)
+            .map_err(|e| format!("Failed to merge staff: {}", e))?
+            .ok_or_else(|| format!("Staff member {} not found", id))?;
+    }
+
+    let conflicts = merge_conflicts(keep_id, merge_id)?;
+    if !conflicts.is_empty() {
+        return Err(format!("Cannot merge these records: {}", conflicts.join("; ")));
+    }
+
+    match merge_staff_in_db(keep_id, merge_id, merged_by.as_deref()) {
+        Ok(merge) => Ok(merge),
+        Err(e) => Err(format!("Failed to merge staff: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_staff_merges() -> Result<Vec<StaffMerge>, String> {
+    match get_staff_merges_from_db() {
+        Ok(merges) => Ok(merges),
+        Err(e) => Err(format!("Failed to get staff merges: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use tauri::command;
+use chrono::{Datelike, Local};
+use crate::database::models::{IncrementDue, IncrementPreview, AppliedIncrement};
+use crate::database::increments::{
+    get_increments_due_from_db, preview_increments_from_db, apply_increments_in_db,
+    get_applied_increments_from_db
+};
+
+fn validate_month(month: u32) -> Result<(), String> {
+    if (1..=12).contains(&month) {
+        Ok(())
+    } else {
+        Err(format!("Invalid month: {}", month))
+    }
+}
+
+#[command]
+pub fn get_increments_due(month: u32, year: Option<i32>) -> Result<Vec<IncrementDue>, String> {
+    validate_month(month)?;
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match get_increments_due_from_db(year, month) {
+        Ok(due) => Ok(due),
+        Err(e) => Err(format!("Failed to get increments due: {}", e)),
+    }
+}
+
+#[command]
+pub fn preview_increments(month: u32, year: Option<i32>) -> Result<IncrementPreview, String> {
+    validate_month(month)?;
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match preview_increments_from_db(year, month) {
+        Ok(preview) => Ok(preview),
+        Err(e) => Err(format!("Failed to preview increments: {}", e)),
+    }
+}
+
+#[command]
+pub fn apply_increments(month: u32, year: Option<i32>, staff_ids: Option<Vec<i32>>) -> Result<String, String> {
+    validate_month(month)?;
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match apply_increments_in_db(year, month, staff_ids.as_deref()) {
+        Ok(applied) => Ok(format!("Increments applied to {} staff members", applied.len())),
+        Err(e) => Err(format!("Failed to apply increments: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_increment_history(staff_id: i32) -> Result<Vec<AppliedIncrement>, String> {
+    match get_applied_increments_from_db(staff_id) {
+        Ok(increments) => Ok(increments),
+        Err(e) => Err(format!("Failed to get increment history: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use tauri::command;
+use chrono::{Datelike, Local, NaiveDate};
+use crate::database::models::{LeaveType, LeaveApplication, LeaveBalance, LeaveRegisterEntry};
+use crate::database::leave::{
+    get_leave_types_from_db, update_leave_type_in_db, insert_leave_application_in_db,
+    get_leave_applications_from_db, get_leave_application_from_db, set_leave_status_in_db,
+    get_leave_balances_from_db, set_leave_entitlement_in_db, carry_forward_leave_in_db,
+    get_leave_register_from_db
+};
+use crate::database::operations::get_staff_by_id_from_db;
+use crate::utils::date_utils::{parse_date, format_date, periods_overlap, working_days_between};
+
+pub fn leave_period(application: &LeaveApplication) -> Result<(NaiveDate, NaiveDate), String> {
+    let from = parse_date(&application.from_date)
+        .ok_or_else(|| format!("Invalid leave start date: {}", application.from_date))?;
+    let to = parse_date(&application.to_date)
+        .ok_or_else(|| format!("Invalid leave end date: {}", application.to_date))?;
+
+    if to < from {
+        return Err("Leave end date cannot be before the start date".to_string());
+    }
+
+    Ok((from, to))
+}
+
+/// Rejects leave that does not fit in the remaining balance for its year
+fn check_balance(application: &LeaveApplication, include_pending: bool) -> Result<(), String> {
+    let (from, _) = leave_period(application)?;
+    let balances = get_leave_balances_from_db(application.staff_id, from.year())
+        .map_err(|e| format!("Failed to get leave balances: {}", e))?;
+
+    let balance = balances.iter()
+        .find(|balance| balance.leave_type_id == application.leave_type_id)
+        .ok_or_else(|| format!("Unknown leave type: {}", application.leave_type_id))?;
+
+    if let Some(available) = balance.available {
+        let remaining = if include_pending { available - balance.pending } else { available };
+        if application.days > remaining {
+            return Err(format!(
+                "Insufficient {} balance: {} days requested, {} available",
+                balance.leave_type, application.days, remaining
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn get_leave_types() -> Result<Vec<LeaveType>, String> {
+    match get_leave_types_from_db() {
+        Ok(types) => Ok(types),
+        Err(e) => Err(format!("Failed to get leave types: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_leave_type(leave_type: LeaveType) -> Result<String, String> {
+    if leave_type.id.is_none() {
+        return Err("Leave type ID is required for update".to_string());
+    }
+
+    if leave_type.annual_entitlement.is_some_and(|days| days < 0.0) || leave_type.max_carry_forward < 0.0 {
+        return Err("Leave entitlements cannot be negative".to_string());
+    }
+
+    match update_leave_type_in_db(&leave_type) {
+        Ok(_) => Ok("Leave type updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update leave type: {}", e)),
+    }
+}
+
+#[command]
+pub fn apply_leave(mut application: LeaveApplication) -> Result<i64, String> {
+    let (from, to) = leave_period(&application)?;
+    application.from_date = format_date(from);
+    application.to_date = format_date(to);
+
+    if from.year() != to.year() {
+        return Err("Leave spanning two years must be applied for separately".to_string());
+    }
+
+    // Days default to the working days in the period; half days are entered explicitly
+    if application.days <= 0.0 {
+        application.days = working_days_between(from, to) as f64;
+    }
+    if application.days <= 0.0 {
+        return Err("Leave period contains no working days".to_string());
+    }
+
+    let existing = get_leave_applications_from_db(application.staff_id)
+        .map_err(|e| format!("Failed to get leave applications: {}", e))?;
+    for other in existing.iter().filter(|other| other.status != "rejected") {
+        let (other_from, other_to) = leave_period(other)?;
+        if periods_overlap(from, Some(to), other_from, Some(other_to)) {
+            return Err(format!(
+                "Leave overlaps an existing application from {} to {}",
+                other.from_date, other.to_date
+            ));
+        }
+    }
+
+    check_balance(&application, true)?;
+
+    match insert_leave_application_in_db(&application) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to apply for leave: {}", e)),
+    }
+}
+
+fn pending_application(id: i32) -> Result<LeaveApplication, String> {
+    let application = get_leave_application_from_db(id)
+        .map_err(|e| format!("Failed to get leave application: {}", e))?
+        .ok_or_else(|| format!("Leave application {} not found", id))?;
+
+    if application.status != "pending" {
+        return Err(format!("Leave application has already been {}", application.status));
+    }
+
+    Ok(application)
+}
+
+#[command]
+pub fn approve_leave(id: i32, approved_by: Option<String>, remarks: Option<String>) -> Result<String, String> {
+    let application = pending_application(id)?;
+    check_balance(&application, false)?;
+
+    match set_leave_status_in_db(id, "approved", approved_by.as_deref(), remarks.as_deref()) {
+        Ok(_) => Ok("Leave approved".to_string()),
+        Err(e) => Err(format!("Failed to approve leave: {}", e)),
+    }
+}
+
+#[command]
+pub fn reject_leave(id: i32, rejected_by: Option<String>, remarks: Option<String>) -> Result<String, String> {
+    pending_application(id)?;
+
+    match set_leave_status_in_db(id, "rejected", rejected_by.as_deref(), remarks.as_deref()) {
+        Ok(_) => Ok("Leave rejected".to_string()),
+        Err(e) => Err(format!("Failed to reject leave: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_leave_applications(staff_id: i32) -> Result<Vec<LeaveApplication>, String> {
+    match get_leave_applications_from_db(staff_id) {
+        Ok(applications) => Ok(applications),
+        Err(e) => Err(format!("Failed to get leave applications: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_leave_balances(staff_id: i32, year: Option<i32>) -> Result<Vec<LeaveBalance>, String> {
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match get_leave_balances_from_db(staff_id, year) {
+        Ok(balances) => Ok(balances),
+        Err(e) => Err(format!("Failed to get leave balances: {}", e)),
+    }
+}
+
+/// Overrides a staff member's entitlement for one year, such as a reduced
+/// casual leave entitlement in the year of first appointment. Pass no
+/// entitlement to return to the leave type's default.
+#[command]
+pub fn set_leave_entitlement(staff_id: i32, leave_type_id: i32, year: i32, entitlement: Option<f64>) -> Result<String, String> {
+    if entitlement.is_some_and(|days| days < 0.0) {
+        return Err("Leave entitlements cannot be negative".to_string());
+    }
+
+    let staff = get_staff_by_id_from_db(staff_id)
+        .map_err(|e| format!("Failed to get staff: {}", e))?
+        .ok_or_else(|| format!("Staff member {} not found", staff_id))?;
+
+    let leave_type = get_leave_types_from_db()
+        .map_err(|e| format!("Failed to get leave types: {}", e))?
+        .into_iter()
+        .find(|leave_type| leave_type.id == Some(leave_type_id))
+        .ok_or_else(|| format!("Unknown leave type: {}", leave_type_id))?;
+
+    match set_leave_entitlement_in_db(staff_id, leave_type_id, year, entitlement) {
+        Ok(_) => Ok(format!("{} entitlement for {} in {} updated", leave_type.name, staff.full_name, year)),
+        Err(e) => Err(format!("Failed to update leave entitlement: {}", e)),
+    }
+}
+
+#[command]
+pub fn carry_forward_leave(year: i32) -> Result<String, String> {
+    match carry_forward_leave_in_db(year) {
+        Ok(count) => Ok(format!("Carried forward {} leave balances into {}", count, year + 1)),
+        Err(e) => Err(format!("Failed to carry forward leave: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_leave_register(month: u32, year: Option<i32>) -> Result<Vec<LeaveRegisterEntry>, String> {
+    if !(1..=12).contains(&month) {
+        return Err(format!("Invalid month: {}", month));
+    }
+
+    let year = year.unwrap_or_else(|| Local::now().year());
+    match get_leave_register_from_db(year, month) {
+        Ok(register) => Ok(register),
+        Err(e) => Err(format!("Failed to get leave register: {}", e)),
+    }
+}
\ No newline at end of file
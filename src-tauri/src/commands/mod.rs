@@ -1 +1,20 @@
-pub mod staff;
\ No newline at end of file
+pub mod staff;
+pub mod reports;
+pub mod increments;
+pub mod salary;
+pub mod designations;
+pub mod postings;
+pub mod promotions;
+pub mod leave;
+pub mod attendance;
+pub mod dependents;
+pub mod qualifications;
+pub mod documents;
+pub mod notes;
+pub mod pension;
+pub mod service;
+pub mod probation;
+pub mod reminders;
+pub mod offices;
+pub mod nic;
+pub mod duplicates;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use tauri::command;
+use chrono::Local;
+use crate::database::models::{NicDetails, NicMismatch, NicNormalization, Staff};
+use crate::database::nic::{get_nic_mismatches_from_db, normalize_nic_numbers_in_db, find_other_staff_with_nic_from_db};
+use crate::utils::nic_converter::{convert_old_to_new_nic, convert_new_to_old_nic, extract_nic_info, nic_mismatches};
+use crate::utils::date_utils::{format_date, years_between};
+
+/// Ways a staff record's date of birth and gender disagree with its NIC
+/// number, joined into one message, or None when they agree
+pub fn nic_warning(staff: &Staff) -> Option<String> {
+    let mismatches = nic_mismatches(staff.nic_number.as_str(), &staff.date_of_birth, &staff.gender);
+    if mismatches.is_empty() {
+        None
+    } else {
+        Some(mismatches.join("; "))
+    }
+}
+
+/// Checks a new staff record's date of birth and gender against its NIC number
+pub fn check_nic(staff: &Staff) -> Result<(), String> {
+    match nic_warning(staff) {
+        Some(warning) => Err(warning),
+        None => Ok(()),
+    }
+}
+
+/// Checks that no other staff record holds the same NIC in either format
+pub fn check_unique_nic(staff: &Staff) -> Result<(), String> {
+    let existing = find_other_staff_with_nic_from_db(staff.nic_number.as_str(), staff.id)
+        .map_err(|e| format!("Failed to check NIC number: {}", e))?;
+
+    match existing {
+        Some((_, appointment_number, full_name)) => Err(format!(
+            "NIC number {} already belongs to {} ({})",
+            staff.nic_number, full_name, appointment_number
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Converts a NIC number of either format to the new 12-digit format
+#[command]
+pub fn convert_nic(nic_number: String) -> Result<String, String> {
+    convert_old_to_new_nic(&nic_number).map_err(|e| format!("{}: {}", e, nic_number.trim()))
+}
+
+/// Converts a NIC number to the old 9 digits + V format used by legacy
+/// payroll files. Fails for NICs that have no old format. The letter is
+/// always V because the new format does not record V or X, so match
+/// legacy records on the first 9 digits only.
+#[command]
+pub fn convert_nic_to_legacy(nic_number: String) -> Result<String, String> {
+    convert_new_to_old_nic(&nic_number).map_err(|e| format!("{}: {}", e, nic_number.trim()))
+}
+
+/// Date of birth, gender and age encoded in a NIC number of either format,
+/// used to fill in the staff form as the NIC is typed
+#[command]
+pub fn get_nic_details(nic_number: String) -> Result<NicDetails, String> {
+    let info = extract_nic_info(&nic_number).map_err(|e| format!("{}: {}", e, nic_number.trim()))?;
+
+    Ok(NicDetails {
+        nic_number: info.new_format,
+        date_of_birth: format_date(info.date_of_birth),
+        gender: info.gender,
+        age: years_between(info.date_of_birth, Local::now().date_naive()),
+    })
+}
+
+/// Existing staff records that disagree with their NIC number
+#[command]
+pub fn get_nic_mismatch_report() -> Result<Vec<NicMismatch>, String> {
+    match get_nic_mismatches_from_db() {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Failed to check NIC numbers: {}", e)),
+    }
+}
+
+/// Converts every stored NIC number still in the old format, reporting the
+/// ones that could not be converted
+#[command]
+pub fn normalize_nic_numbers() -> Result<NicNormalization, String> {
+    match normalize_nic_numbers_in_db() {
+        Ok(normalization) => Ok(normalization),
+        Err(e) => Err(format!("Failed to normalize NIC numbers: {}", e)),
+    }
+}
\ No newline at end of file
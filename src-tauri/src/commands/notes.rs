@@ -0,0 +1,93 @@
+use tauri::command;
+use uuid::Uuid;
+use crate::database::models::StaffNote;
+use crate::database::notes::{
+    NOTE_CATEGORIES, get_notes_access_from_db, set_notes_access_in_db, get_notes_from_db,
+    insert_note_in_db
+};
+use crate::utils::date_utils::{parse_date, format_date};
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use argon2::password_hash::SaltString;
+
+const MIN_PASSPHRASE_LENGTH: usize = 6;
+
+/// Stores the passphrase as an Argon2 hash, which carries its own salt and
+/// parameters
+fn save_passphrase(passphrase: &str) -> Result<(), String> {
+    let salt = SaltString::encode_b64(Uuid::new_v4().as_bytes())
+        .map_err(|e| format!("Failed to hash notes passphrase: {}", e))?;
+    let hash = Argon2::default()
+        .hash_password(passphrase.as_bytes(), &salt)
+        .map_err(|e| format!("Failed to hash notes passphrase: {}", e))?
+        .to_string();
+
+    set_notes_access_in_db(&hash)
+        .map_err(|e| format!("Failed to save notes passphrase: {}", e))
+}
+
+/// Checks the passphrase that unlocks the notes log. This only gates access
+/// within the app; the notes are not encrypted in the database.
+pub fn verify_notes_passphrase(passphrase: &str) -> Result<(), String> {
+    let hash = get_notes_access_from_db()
+        .map_err(|e| format!("Failed to check notes access: {}", e))?
+        .ok_or("Set a passphrase for the notes log before using it")?;
+
+    let hash = PasswordHash::new(&hash)
+        .map_err(|e| format!("Failed to check notes access: {}", e))?;
+
+    match Argon2::default().verify_password(passphrase.as_bytes(), &hash) {
+        Ok(_) => Ok(()),
+        Err(_) => Err("Incorrect passphrase for the notes log".to_string()),
+    }
+}
+
+/// Sets or changes the notes log passphrase. Changing it requires the current one.
+#[command]
+pub fn set_notes_passphrase(current_passphrase: Option<String>, new_passphrase: String) -> Result<String, String> {
+    let has_passphrase = get_notes_access_from_db()
+        .map_err(|e| format!("Failed to check notes access: {}", e))?
+        .is_some();
+
+    if has_passphrase {
+        verify_notes_passphrase(current_passphrase.as_deref().unwrap_or_default())?;
+    }
+
+    if new_passphrase.chars().count() < MIN_PASSPHRASE_LENGTH {
+        return Err(format!("Passphrase must be at least {} characters", MIN_PASSPHRASE_LENGTH));
+    }
+
+    save_passphrase(&new_passphrase)?;
+    Ok("Notes passphrase saved".to_string())
+}
+
+#[command]
+pub fn add_staff_note(mut note: StaffNote, passphrase: String) -> Result<i64, String> {
+    verify_notes_passphrase(&passphrase)?;
+
+    if !NOTE_CATEGORIES.contains(&note.category.as_str()) {
+        return Err(format!("Invalid note category: {}", note.category));
+    }
+
+    if note.description.trim().is_empty() {
+        return Err("Description is required".to_string());
+    }
+
+    note.note_date = format_date(
+        parse_date(&note.note_date).ok_or_else(|| format!("Invalid note date: {}", note.note_date))?,
+    );
+
+    match insert_note_in_db(&note) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add note: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_staff_notes(staff_id: i32, passphrase: String) -> Result<Vec<StaffNote>, String> {
+    verify_notes_passphrase(&passphrase)?;
+
+    match get_notes_from_db(staff_id) {
+        Ok(notes) => Ok(notes),
+        Err(e) => Err(format!("Failed to get notes: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use tauri::command;
+use crate::database::models::{Office, Staff};
+use crate::database::offices::{
+    get_offices_from_db, get_office_from_db, insert_office_in_db, update_office_in_db,
+    count_office_staff_from_db, delete_office_from_db, set_default_office_in_db
+};
+
+fn load_office(id: i32) -> Result<Office, String> {
+    get_office_from_db(id)
+        .map_err(|e| format!("Failed to get office: {}", e))?
+        .ok_or_else(|| format!("Office {} not found", id))
+}
+
+/// Checks the office a staff record is linked to exists. Records without an
+/// office are placed in the default office when added.
+pub fn check_office(staff: &Staff) -> Result<(), String> {
+    match staff.office_id {
+        Some(id) => load_office(id).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+fn validate_office(office: &mut Office) -> Result<(), String> {
+    office.code = office.code.trim().to_uppercase();
+
+    if office.code.is_empty() {
+        return Err("Office code is required".to_string());
+    }
+
+    if office.name.trim().is_empty() {
+        return Err("Office name is required".to_string());
+    }
+
+    if office.letterhead_title.trim().is_empty() {
+        return Err("Letterhead title is required".to_string());
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn get_offices() -> Result<Vec<Office>, String> {
+    match get_offices_from_db() {
+        Ok(offices) => Ok(offices),
+        Err(e) => Err(format!("Failed to get offices: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_office(mut office: Office) -> Result<String, String> {
+    validate_office(&mut office)?;
+    match insert_office_in_db(&office) {
+        Ok(id) => Ok(format!("Office added successfully with ID: {}", id)),
+        Err(e) => Err(format!("Failed to add office: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_office(mut office: Office) -> Result<String, String> {
+    validate_office(&mut office)?;
+    match update_office_in_db(&office) {
+        Ok(_) => Ok("Office updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update office: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_office(id: i32) -> Result<String, String> {
+    let office = load_office(id)?;
+
+    if office.is_default {
+        return Err(format!("{} is the default office and cannot be deleted", office.name));
+    }
+
+    let staff_count = count_office_staff_from_db(id).map_err(|e| format!("Failed to delete office: {}", e))?;
+    if staff_count > 0 {
+        return Err(format!("{} still has {} staff members; move them to another office first", office.name, staff_count));
+    }
+
+    match delete_office_from_db(id) {
+        Ok(_) => Ok("Office deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete office: {}", e)),
+    }
+}
+
+/// Makes an office the default, used for new staff and for the letterhead
+/// of reports covering every office
+#[command]
+pub fn set_default_office(id: i32) -> Result<String, String> {
+    let office = load_office(id)?;
+
+    match set_default_office_in_db(id) {
+        Ok(_) => Ok(format!("{} is now the default office", office.name)),
+        Err(e) => Err(format!("Failed to set default office: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use tauri::command;
+use chrono::NaiveDate;
+use crate::database::models::{PensionRules, PensionEstimate};
+use crate::database::pension::{get_pension_rules_from_db, update_pension_rules_in_db, get_pension_estimate_from_db};
+use crate::database::operations::get_staff_by_id_from_db;
+use crate::database::offices::get_letterhead_from_db;
+use crate::utils::date_utils::parse_date;
+use crate::utils::export::{write_pdf_report, PdfTable};
+
+fn load_estimate(staff_id: i32, retirement_date: Option<String>) -> Result<PensionEstimate, String> {
+    let retirement: Option<NaiveDate> = match retirement_date.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => {
+            Some(parse_date(date).ok_or_else(|| format!("Invalid retirement date: {}", date))?)
+        }
+        _ => None,
+    };
+
+    get_pension_estimate_from_db(staff_id, retirement)
+        .map_err(|e| format!("Failed to estimate pension: {}", e))?
+        .ok_or_else(|| "Pension cannot be estimated: staff member not found or dates are incomplete".to_string())
+}
+
+#[command]
+pub fn get_pension_rules() -> Result<PensionRules, String> {
+    match get_pension_rules_from_db() {
+        Ok(rules) => Ok(rules),
+        Err(e) => Err(format!("Failed to get pension rules: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_pension_rules(rules: PensionRules) -> Result<String, String> {
+    if rules.minimum_service_years < 0 || rules.commutation_years < 0 {
+        return Err("Service periods cannot be negative".to_string());
+    }
+
+    let percentages = [rules.base_percentage, rules.percentage_per_year, rules.maximum_percentage, rules.commutation_reduction];
+    if percentages.iter().any(|p| !(0.0..=100.0).contains(p)) {
+        return Err("Percentages must be between 0 and 100".to_string());
+    }
+
+    if rules.base_percentage > rules.maximum_percentage {
+        return Err("Base percentage cannot exceed the maximum percentage".to_string());
+    }
+
+    if rules.gratuity_months < 0.0 || rules.short_service_gratuity_months < 0.0 {
+        return Err("Gratuity months cannot be negative".to_string());
+    }
+
+    match update_pension_rules_in_db(&rules) {
+        Ok(_) => Ok("Pension rules updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update pension rules: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_pension_estimate(staff_id: i32, retirement_date: Option<String>) -> Result<PensionEstimate, String> {
+    load_estimate(staff_id, retirement_date)
+}
+
+/// Writes a printable pension statement for one staff member
+#[command]
+pub fn export_pension_statement(
+    staff_id: i32,
+    retirement_date: Option<String>,
+    output_path: String,
+) -> Result<String, String> {
+    let estimate = load_estimate(staff_id, retirement_date)?;
+    let rules = get_pension_rules_from_db().map_err(|e| format!("Failed to get pension rules: {}", e))?;
+
+    let office_id = get_staff_by_id_from_db(staff_id)
+        .map_err(|e| format!("Failed to get staff: {}", e))?
+        .and_then(|staff| staff.office_id);
+    let letterhead = get_letterhead_from_db(office_id)
+        .map_err(|e| format!("Failed to get office letterhead: {}", e))?;
+
+    let row = |label: &str, value: String| vec![label.to_string(), value];
+    let mut benefits = vec![
+        row("Pensionable Service", format!("{} years {} months", estimate.service_years, estimate.service_months)),
+        row("Last Drawn Basic Salary", format!("{:.2}", estimate.last_basic_salary)),
+    ];
+
+    if estimate.pensionable {
+        benefits.extend([
+            row("Pension Percentage", format!("{:.2}%", estimate.pension_percentage)),
+            row("Monthly Pension", format!("{:.2}", estimate.monthly_pension)),
+            row("Commuted Gratuity", format!("{:.2}", estimate.gratuity)),
+            row("Reduced Monthly Pension", format!("{:.2}", estimate.reduced_monthly_pension)),
+            row("Full Pension From", estimate.full_pension_from.clone().unwrap_or_default()),
+        ]);
+    } else {
+        benefits.extend([
+            row("Pension", format!("Not eligible (minimum {} years of service)", rules.minimum_service_years)),
+            row("Service Gratuity", format!("{:.2}", estimate.gratuity)),
+        ]);
+    }
+
+    let tables = vec![
+        PdfTable {
+            heading: Some("Officer".to_string()),
+            headers: Vec::new(),
+            widths: vec![60.0, 120.0],
+            rows: vec![
+                row("Appointment Number", estimate.appointment_number.clone()),
+                row("Full Name", estimate.full_name.clone()),
+                row("Designation", estimate.designation.clone()),
+                row("Date of First Appointment", estimate.date_of_first_appointment.clone()),
+                row("Date of Retirement", estimate.retirement_date.clone()),
+            ],
+        },
+        PdfTable {
+            heading: Some("Estimated Benefits".to_string()),
+            headers: Vec::new(),
+            widths: vec![60.0, 120.0],
+            rows: benefits,
+        },
+        PdfTable {
+            heading: None,
+            headers: Vec::new(),
+            widths: vec![180.0],
+            rows: vec![vec![
+                "This is an estimate only. The final award is determined by the Department of Pensions.".to_string(),
+            ]],
+        },
+    ];
+
+    match write_pdf_report(&output_path, &letterhead, "Pension Estimate", &estimate.full_name, &tables) {
+        Ok(_) => Ok(format!("Pension statement exported to {}", output_path)),
+        Err(e) => Err(format!("Failed to export pension statement: {}", e)),
+    }
+}
\ No newline at end of file
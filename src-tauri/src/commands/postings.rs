@@ -0,0 +1,166 @@
+use tauri::command;
+use chrono::{Days, Local, NaiveDate};
+use crate::database::models::{Posting, TransferOrder, StationStaff};
+use crate::database::postings::{
+    TRANSFER_TYPES, get_postings_from_db, insert_posting_in_db, record_transfer_in_db,
+    update_posting_in_db, delete_posting_from_db, get_station_postings_from_db
+};
+use crate::utils::date_utils::{parse_date, format_date, periods_overlap};
+
+pub fn posting_period(posting: &Posting) -> Result<(NaiveDate, Option<NaiveDate>), String> {
+    let from = parse_date(&posting.from_date)
+        .ok_or_else(|| format!("Invalid posting start date: {}", posting.from_date))?;
+
+    let to = match posting.to_date.as_deref() {
+        Some(to_date) if !to_date.trim().is_empty() => {
+            Some(parse_date(to_date).ok_or_else(|| format!("Invalid posting end date: {}", to_date))?)
+        }
+        _ => None,
+    };
+
+    if let Some(to) = to {
+        if to < from {
+            return Err("Posting end date cannot be before the start date".to_string());
+        }
+    }
+
+    Ok((from, to))
+}
+
+fn validate_posting(posting: &Posting) -> Result<(), String> {
+    if posting.station.trim().is_empty() {
+        return Err("Station is required".to_string());
+    }
+
+    if !TRANSFER_TYPES.contains(&posting.transfer_type.as_str()) {
+        return Err(format!("Invalid transfer type: {}", posting.transfer_type));
+    }
+
+    posting_period(posting).map(|_| ())
+}
+
+/// Rejects a posting that overlaps any other posting of the same person
+fn check_overlap(posting: &Posting, existing: &[Posting]) -> Result<(), String> {
+    let (from, to) = posting_period(posting)?;
+
+    for other in existing.iter().filter(|other| other.id.is_none() || other.id != posting.id) {
+        let (other_from, other_to) = posting_period(other)?;
+        if periods_overlap(from, to, other_from, other_to) {
+            return Err(format!(
+                "Posting overlaps the posting at {} from {}",
+                other.station, other.from_date
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn load_postings(staff_id: i32) -> Result<Vec<Posting>, String> {
+    get_postings_from_db(staff_id).map_err(|e| format!("Failed to get postings: {}", e))
+}
+
+#[command]
+pub fn get_staff_postings(staff_id: i32) -> Result<Vec<Posting>, String> {
+    let mut postings = load_postings(staff_id)?;
+    postings.sort_by_key(|posting| parse_date(&posting.from_date));
+    Ok(postings)
+}
+
+#[command]
+pub fn add_posting(posting: Posting) -> Result<String, String> {
+    validate_posting(&posting)?;
+    check_overlap(&posting, &load_postings(posting.staff_id)?)?;
+    match insert_posting_in_db(&posting) {
+        Ok(id) => Ok(format!("Posting added successfully with ID: {}", id)),
+        Err(e) => Err(format!("Failed to add posting: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_posting(posting: Posting) -> Result<String, String> {
+    if posting.id.is_none() {
+        return Err("Posting ID is required".to_string());
+    }
+    validate_posting(&posting)?;
+    check_overlap(&posting, &load_postings(posting.staff_id)?)?;
+    match update_posting_in_db(&posting) {
+        Ok(_) => Ok("Posting updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update posting: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_posting(id: i32) -> Result<String, String> {
+    match delete_posting_from_db(id) {
+        Ok(_) => Ok("Posting deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete posting: {}", e)),
+    }
+}
+
+/// Transfers a staff member: the current posting ends the day before the
+/// effective date and a new open posting starts on it
+#[command]
+pub fn record_transfer(order: TransferOrder) -> Result<String, String> {
+    let effective = parse_date(&order.effective_date)
+        .ok_or_else(|| format!("Invalid effective date: {}", order.effective_date))?;
+
+    let mut existing = load_postings(order.staff_id)?;
+    let mut close = None;
+
+    if let Some(current) = existing.iter_mut().find(|posting| posting.to_date.is_none()) {
+        let (current_from, _) = posting_period(current)?;
+        if effective <= current_from {
+            return Err(format!(
+                "Transfer date must be after the start of the current posting at {} ({})",
+                current.station, current.from_date
+            ));
+        }
+
+        let closing_date = format_date(effective - Days::new(1));
+        current.to_date = Some(closing_date.clone());
+        close = current.id.map(|id| (id, closing_date));
+    }
+
+    let posting = Posting {
+        id: None,
+        staff_id: order.staff_id,
+        station: order.station.trim().to_string(),
+        station_type: order.station_type,
+        from_date: order.effective_date,
+        to_date: None,
+        order_reference: order.order_reference,
+        transfer_type: order.transfer_type,
+        remarks: order.remarks,
+        created_at: None,
+        updated_at: None,
+    };
+    validate_posting(&posting)?;
+    check_overlap(&posting, &existing)?;
+
+    match record_transfer_in_db(close, &posting) {
+        Ok(_) => Ok(format!("Transfer to {} recorded successfully", posting.station)),
+        Err(e) => Err(format!("Failed to record transfer: {}", e)),
+    }
+}
+
+/// Staff posted to a station on the given date (today by default)
+#[command]
+pub fn get_staff_at_station(station: String, date: Option<String>) -> Result<Vec<StationStaff>, String> {
+    let on = match date {
+        Some(date) => parse_date(&date).ok_or_else(|| format!("Invalid date: {}", date))?,
+        None => Local::now().date_naive(),
+    };
+
+    let postings = get_station_postings_from_db(&station)
+        .map_err(|e| format!("Failed to get staff at station: {}", e))?;
+
+    Ok(postings
+        .into_iter()
+        .filter(|posting| {
+            let from = parse_date(&posting.from_date);
+            let to = posting.to_date.as_deref().and_then(parse_date);
+            from.is_some_and(|from| from <= on) && to.is_none_or(|to| on <= to)
+        })
+        .collect())
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use tauri::command;
+use chrono::{Days, Local, Months};
+use crate::database::models::{Staff, ProbationDue};
+use crate::database::operations::get_staff_by_id_from_db;
+use crate::database::probation::{get_probation_due_from_db, confirm_probation_in_db, clear_probation_in_db};
+use crate::utils::date_utils::{parse_date, format_date};
+
+/// Validates the probation details on a staff record, working out the end
+/// date from the period when only the period is given
+pub fn apply_probation(staff: &mut Staff) -> Result<(), String> {
+    if staff.probation_period_months.is_some_and(|months| months <= 0) {
+        return Err("Probation period must be at least one month".to_string());
+    }
+
+    staff.probation_end_date = match staff.probation_end_date.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => Some(format_date(
+            parse_date(date).ok_or_else(|| format!("Invalid probation end date: {}", date))?,
+        )),
+        _ => match (staff.probation_period_months, parse_date(&staff.date_of_first_appointment)) {
+            (Some(months), Some(appointed)) => appointed
+                .checked_add_months(Months::new(months as u32))
+                .map(format_date),
+            _ => None,
+        },
+    };
+
+    Ok(())
+}
+
+/// Unconfirmed staff whose probation ends within `days` days (30 by default)
+/// or has already lapsed
+#[command]
+pub fn get_probation_due(days: Option<u64>) -> Result<Vec<ProbationDue>, String> {
+    let today = Local::now().date_naive();
+    let until = today
+        .checked_add_days(Days::new(days.unwrap_or(30)))
+        .ok_or("Invalid number of days")?;
+
+    match get_probation_due_from_db(today, until) {
+        Ok(due) => Ok(due),
+        Err(e) => Err(format!("Failed to get probation list: {}", e)),
+    }
+}
+
+fn load_staff(staff_id: i32) -> Result<Staff, String> {
+    get_staff_by_id_from_db(staff_id)
+        .map_err(|e| format!("Failed to get staff: {}", e))?
+        .ok_or_else(|| format!("Staff member {} not found", staff_id))
+}
+
+#[command]
+pub fn confirm_probation(staff_id: i32, confirmation_date: String, reference: String) -> Result<String, String> {
+    let staff = load_staff(staff_id)?;
+
+    if let Some(ref confirmed) = staff.confirmation_date {
+        return Err(format!("{} was already confirmed on {}", staff.full_name, confirmed));
+    }
+
+    let date = parse_date(&confirmation_date)
+        .ok_or_else(|| format!("Invalid confirmation date: {}", confirmation_date))?;
+
+    if parse_date(&staff.date_of_first_appointment).is_some_and(|appointed| date < appointed) {
+        return Err("Confirmation date cannot be before the date of first appointment".to_string());
+    }
+
+    if reference.trim().is_empty() {
+        return Err("Confirmation letter reference is required".to_string());
+    }
+
+    match confirm_probation_in_db(staff_id, &format_date(date), reference.trim()) {
+        Ok(_) => Ok(format!("{} confirmed in service", staff.full_name)),
+        Err(e) => Err(format!("Failed to confirm probation: {}", e)),
+    }
+}
+
+/// Takes a probation entered by mistake off a staff record. Staff already
+/// confirmed in service keep the probation they served.
+#[command]
+pub fn clear_probation(staff_id: i32) -> Result<String, String> {
+    let staff = load_staff(staff_id)?;
+
+    if let Some(ref confirmed) = staff.confirmation_date {
+        return Err(format!("{} was confirmed on {}; their probation cannot be cleared", staff.full_name, confirmed));
+    }
+
+    match clear_probation_in_db(staff_id) {
+        Ok(_) => Ok(format!("Probation cleared for {}", staff.full_name)),
+        Err(e) => Err(format!("Failed to clear probation: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use tauri::command;
+use chrono::Local;
+use crate::database::models::{Promotion, PromotionRequest, SeniorityEntry};
+use crate::database::operations::{get_staff_by_id_from_db, get_all_staff_from_db};
+use crate::database::designations::find_designation_from_db;
+use crate::database::promotions::{
+    promote_staff_in_db, get_promotions_for_staff_from_db, get_promotions_to_designation_from_db
+};
+use crate::database::salary::get_current_salary_scale_from_db;
+use crate::commands::salary::apply_salary_scale;
+use crate::utils::date_utils::parse_date;
+
+#[command]
+pub fn promote_staff(request: PromotionRequest) -> Result<String, String> {
+    if parse_date(&request.effective_date).is_none() {
+        return Err(format!("Invalid effective date: {}", request.effective_date));
+    }
+
+    if request.approval_reference.trim().is_empty() {
+        return Err("Approval reference is required".to_string());
+    }
+
+    let staff = get_staff_by_id_from_db(request.staff_id)
+        .map_err(|e| format!("Failed to get staff: {}", e))?
+        .ok_or_else(|| format!("Staff member {} not found", request.staff_id))?;
+
+    let designation = find_designation_from_db(&request.to_designation)
+        .map_err(|e| format!("Failed to get designation: {}", e))?
+        .ok_or_else(|| format!("Unknown designation: {}", request.to_designation))?;
+
+    if designation.id == staff.designation_id && request.to_salary_code == staff.salary_code {
+        return Err(format!("{} already holds the designation {}", staff.full_name, designation.name));
+    }
+
+    let has_scale = get_current_salary_scale_from_db(&request.to_salary_code)
+        .map_err(|e| format!("Failed to get salary scale: {}", e))?
+        .is_some();
+
+    let mut promoted = staff.clone();
+    promoted.designation = designation.name.clone();
+    promoted.designation_id = designation.id;
+    promoted.salary_code = request.to_salary_code.clone();
+    promoted.basic_salary = request.basic_salary.unwrap_or(staff.basic_salary);
+    promoted.increment_amount = match request.increment_amount {
+        Some(amount) => amount,
+        None if has_scale => 0.0, // Filled in from the new scale below
+        None => staff.increment_amount,
+    };
+    apply_salary_scale(&mut promoted)?;
+
+    let promotion = Promotion {
+        id: None,
+        staff_id: staff.id.unwrap_or(request.staff_id),
+        from_designation: staff.designation,
+        from_designation_id: staff.designation_id,
+        to_designation: designation.name,
+        to_designation_id: designation.id,
+        from_salary_code: staff.salary_code,
+        to_salary_code: request.to_salary_code,
+        basic_salary: promoted.basic_salary,
+        effective_date: request.effective_date,
+        approval_reference: request.approval_reference.trim().to_string(),
+        remarks: request.remarks,
+        created_at: None,
+    };
+
+    let effective = parse_date(&promotion.effective_date);
+    match promote_staff_in_db(&promotion, &promoted) {
+        Ok(_) if effective > Some(Local::now().date_naive()) => Ok(format!(
+            "{} will be promoted to {} on {}",
+            promoted.full_name, promotion.to_designation, promotion.effective_date
+        )),
+        Ok(_) => Ok(format!("{} promoted to {}", promoted.full_name, promotion.to_designation)),
+        Err(e) => Err(format!("Failed to promote staff: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_promotion_history(staff_id: i32) -> Result<Vec<Promotion>, String> {
+    match get_promotions_for_staff_from_db(staff_id) {
+        Ok(mut promotions) => {
+            promotions.sort_by_key(|promotion| parse_date(&promotion.effective_date));
+            Ok(promotions)
+        }
+        Err(e) => Err(format!("Failed to get promotion history: {}", e)),
+    }
+}
+
+/// Staff currently holding a designation, most senior first. Seniority runs
+/// from the date of promotion to the designation, or the date of first
+/// appointment for those appointed directly to it.
+#[command]
+pub fn get_seniority_list(designation: String) -> Result<Vec<SeniorityEntry>, String> {
+    let designation = find_designation_from_db(&designation)
+        .map_err(|e| format!("Failed to get designation: {}", e))?
+        .ok_or_else(|| format!("Unknown designation: {}", designation))?;
+    let designation_id = designation.id.unwrap_or_default();
+
+    let promoted_on: HashMap<i32, String> = get_promotions_to_designation_from_db(designation_id)
+        .map_err(|e| format!("Failed to get promotions: {}", e))?
+        .into_iter()
+        .map(|promotion| (promotion.staff_id, promotion.effective_date))
+        .collect();
+
+    let mut entries: Vec<SeniorityEntry> = get_all_staff_from_db()
+        .map_err(|e| format!("Failed to get staff: {}", e))?
+        .into_iter()
+        .filter(|staff| staff.designation_id == Some(designation_id))
+        .map(|staff| {
+            let staff_id = staff.id.unwrap_or_default();
+            SeniorityEntry {
+                rank: 0,
+                staff_id,
+                appointment_number: staff.appointment_number,
+                full_name: staff.full_name,
+                designation: staff.designation,
+                in_designation_since: promoted_on
+                    .get(&staff_id)
+                    .cloned()
+                    .unwrap_or_else(|| staff.date_of_first_appointment.clone()),
+                date_of_first_appointment: staff.date_of_first_appointment,
+            }
+        })
+        .collect();
+
+    entries.sort_by_key(|entry| {
+        (parse_date(&entry.in_designation_since), parse_date(&entry.date_of_first_appointment))
+    });
+    for (index, entry) in entries.iter_mut().enumerate() {
+        entry.rank = index as i32 + 1;
+    }
+
+    Ok(entries)
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use tauri::command;
+use crate::database::models::{Qualification, TrainingCourse};
+use crate::database::qualifications::{
+    QUALIFICATION_CATEGORIES, get_qualifications_from_db, insert_qualification_in_db,
+    update_qualification_in_db, delete_qualification_from_db, get_training_courses_from_db,
+    insert_training_course_in_db, update_training_course_in_db, delete_training_course_from_db
+};
+use crate::utils::date_utils::{parse_date, format_date};
+
+/// Normalizes an optional dd-MM-yyyy date, treating blank as not given
+fn optional_date(date: Option<&str>, label: &str) -> Result<Option<String>, String> {
+    match date.map(str::trim) {
+        Some(date) if !date.is_empty() => parse_date(date)
+            .map(|date| Some(format_date(date)))
+            .ok_or_else(|| format!("Invalid {}: {}", label, date)),
+        _ => Ok(None),
+    }
+}
+
+fn prepare_qualification(qualification: &mut Qualification) -> Result<(), String> {
+    qualification.category = qualification.category.trim().to_lowercase();
+    if !QUALIFICATION_CATEGORIES.contains(&qualification.category.as_str()) {
+        return Err(format!("Invalid qualification category: {}", qualification.category));
+    }
+
+    if qualification.title.trim().is_empty() {
+        return Err("Qualification title is required".to_string());
+    }
+
+    if qualification.institution.trim().is_empty() {
+        return Err("Institution is required".to_string());
+    }
+
+    qualification.awarded_date = optional_date(qualification.awarded_date.as_deref(), "award date")?;
+    Ok(())
+}
+
+fn prepare_training_course(course: &mut TrainingCourse) -> Result<(), String> {
+    if course.course_name.trim().is_empty() {
+        return Err("Course name is required".to_string());
+    }
+
+    if course.institution.trim().is_empty() {
+        return Err("Institution is required".to_string());
+    }
+
+    let start = parse_date(&course.start_date)
+        .ok_or_else(|| format!("Invalid course start date: {}", course.start_date))?;
+    course.start_date = format_date(start);
+    course.end_date = optional_date(course.end_date.as_deref(), "course end date")?;
+
+    if let Some(end) = course.end_date.as_deref().and_then(parse_date) {
+        if end < start {
+            return Err("Course end date cannot be before the start date".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn get_qualifications(staff_id: i32) -> Result<Vec<Qualification>, String> {
+    match get_qualifications_from_db(staff_id) {
+        Ok(qualifications) => Ok(qualifications),
+        Err(e) => Err(format!("Failed to get qualifications: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_qualification(mut qualification: Qualification) -> Result<i64, String> {
+    prepare_qualification(&mut qualification)?;
+
+    match insert_qualification_in_db(&qualification) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add qualification: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_qualification(mut qualification: Qualification) -> Result<String, String> {
+    if qualification.id.is_none() {
+        return Err("Qualification ID is required for update".to_string());
+    }
+
+    prepare_qualification(&mut qualification)?;
+
+    match update_qualification_in_db(&qualification) {
+        Ok(_) => Ok("Qualification updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update qualification: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_qualification(id: i32) -> Result<String, String> {
+    match delete_qualification_from_db(id) {
+        Ok(_) => Ok("Qualification deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete qualification: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_training_courses(staff_id: i32) -> Result<Vec<TrainingCourse>, String> {
+    match get_training_courses_from_db(staff_id) {
+        Ok(courses) => Ok(courses),
+        Err(e) => Err(format!("Failed to get training courses: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_training_course(mut course: TrainingCourse) -> Result<i64, String> {
+    prepare_training_course(&mut course)?;
+
+    match insert_training_course_in_db(&course) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add training course: {}", e)),
+    }
+}
+
+#[command]
+pub fn update_training_course(mut course: TrainingCourse) -> Result<String, String> {
+    if course.id.is_none() {
+        return Err("Training course ID is required for update".to_string());
+    }
+
+    prepare_training_course(&mut course)?;
+
+    match update_training_course_in_db(&course) {
+        Ok(_) => Ok("Training course updated successfully".to_string()),
+        Err(e) => Err(format!("Failed to update training course: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_training_course(id: i32) -> Result<String, String> {
+    match delete_training_course_from_db(id) {
+        Ok(_) => Ok("Training course deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete training course: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,191 @@
+use std::thread;
+use std::time::Duration;
+use tauri::{
+    command, AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem
+};
+use tauri::api::notification::Notification;
+use chrono::{Days, Local, NaiveDate};
+use crate::database::models::Reminder;
+use crate::database::salary::sync_current_salaries_in_db;
+use crate::database::promotions::apply_due_promotions_in_db;
+use crate::database::reminders::{get_reminders_from_db, set_reminder_state_in_db, clear_reminder_state_in_db};
+use crate::utils::date_utils::format_date;
+
+const TRAY_SUMMARY: &str = "reminders";
+const TRAY_SHOW: &str = "show";
+const TRAY_QUIT: &str = "quit";
+
+/// How often the background check wakes to see whether the day has changed
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Most reminders listed by name in a single notification
+const NOTIFICATION_LIMIT: usize = 5;
+
+fn summary(count: usize) -> String {
+    match count {
+        0 => "No reminders due".to_string(),
+        1 => "1 reminder due".to_string(),
+        _ => format!("{} reminders due", count),
+    }
+}
+
+pub fn reminder_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(TRAY_SUMMARY, summary(0)).disabled())
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(TRAY_SHOW, "Open Staff Manager"))
+        .add_item(CustomMenuItem::new(TRAY_QUIT, "Quit"));
+
+    SystemTray::new().with_menu(menu)
+}
+
+fn show_main_window(app_handle: &AppHandle) {
+    if let Some(window) = app_handle.get_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+pub fn handle_tray_event(app_handle: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } => show_main_window(app_handle),
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            TRAY_SUMMARY | TRAY_SHOW => show_main_window(app_handle),
+            TRAY_QUIT => app_handle.exit(0),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn active_reminders(today: NaiveDate) -> Result<Vec<Reminder>, String> {
+    get_reminders_from_db(today, false).map_err(|e| format!("Failed to get reminders: {}", e))
+}
+
+/// Shows the number of active reminders on the tray icon
+fn refresh_tray(app_handle: &AppHandle, reminders: &[Reminder]) {
+    let text = summary(reminders.len());
+    let tray = app_handle.tray_handle();
+    let _ = tray.set_tooltip(&format!("Forest Office Staff Manager - {}", text));
+    let _ = tray.get_item(TRAY_SUMMARY).set_title(text);
+}
+
+fn notify(app_handle: &AppHandle, reminders: &[Reminder]) {
+    if reminders.is_empty() {
+        return;
+    }
+
+    let mut body: Vec<String> = reminders.iter()
+        .take(NOTIFICATION_LIMIT)
+        .map(|reminder| reminder.title.clone())
+        .collect();
+    if reminders.len() > NOTIFICATION_LIMIT {
+        body.push(format!("and {} more", reminders.len() - NOTIFICATION_LIMIT));
+    }
+
+    let result = Notification::new(&app_handle.config().tauri.bundle.identifier)
+        .title(summary(reminders.len()))
+        .body(body.join("\n"))
+        .show();
+    if let Err(e) = result {
+        eprintln!("Failed to show reminder notification: {}", e);
+    }
+}
+
+/// Checks reminders on startup and again each time the date changes,
+/// notifying from the tray when any are due
+pub fn start_reminder_engine(app_handle: AppHandle) {
+    thread::spawn(move || {
+        let mut last_checked: Option<NaiveDate> = None;
+
+        loop {
+            let today = Local::now().date_naive();
+            if last_checked != Some(today) {
+                // Promotions and salary changes recorded in advance take
+                // effect on their date
+                if let Err(e) = apply_due_promotions_in_db(today) {
+                    eprintln!("Failed to apply promotions: {}", e);
+                }
+                if let Err(e) = sync_current_salaries_in_db(today) {
+                    eprintln!("Failed to bring salaries up to date: {}", e);
+                }
+
+                match active_reminders(today) {
+                    Ok(reminders) => {
+                        refresh_tray(&app_handle, &reminders);
+                        notify(&app_handle, &reminders);
+                        last_checked = Some(today);
+                    }
+                    Err(e) => eprintln!("{}", e),
+                }
+            }
+
+            thread::sleep(CHECK_INTERVAL);
+        }
+    });
+}
+
+/// Reminders due today. Snoozed and dismissed reminders are only included
+/// when `include_hidden` is set.
+#[command]
+pub fn get_reminders(include_hidden: Option<bool>) -> Result<Vec<Reminder>, String> {
+    let today = Local::now().date_naive();
+
+    match get_reminders_from_db(today, include_hidden.unwrap_or(false)) {
+        Ok(reminders) => Ok(reminders),
+        Err(e) => Err(format!("Failed to get reminders: {}", e)),
+    }
+}
+
+/// Looks up a current reminder by key, hidden or not
+fn find_reminder(key: &str) -> Result<Reminder, String> {
+    get_reminders_from_db(Local::now().date_naive(), true)
+        .map_err(|e| format!("Failed to get reminders: {}", e))?
+        .into_iter()
+        .find(|reminder| reminder.key == key)
+        .ok_or_else(|| format!("Reminder {} is no longer due", key))
+}
+
+/// Applies a change of reminder state and brings the tray count up to date
+fn update_state(app_handle: &AppHandle, result: rusqlite::Result<usize>, action: &str) -> Result<(), String> {
+    result.map_err(|e| format!("Failed to {} reminder: {}", action, e))?;
+
+    if let Ok(reminders) = active_reminders(Local::now().date_naive()) {
+        refresh_tray(app_handle, &reminders);
+    }
+    Ok(())
+}
+
+#[command]
+pub fn snooze_reminder(app_handle: AppHandle, key: String, days: u64) -> Result<String, String> {
+    let reminder = find_reminder(&key)?;
+
+    if days == 0 {
+        return Err("Reminders must be snoozed for at least one day".to_string());
+    }
+
+    let until = Local::now().date_naive()
+        .checked_add_days(Days::new(days))
+        .map(format_date)
+        .ok_or("Invalid number of days")?;
+
+    update_state(&app_handle, set_reminder_state_in_db(&reminder.key, Some(&until)), "snooze")?;
+    Ok(format!("Reminder snoozed until {}", until))
+}
+
+#[command]
+pub fn dismiss_reminder(app_handle: AppHandle, key: String) -> Result<String, String> {
+    let reminder = find_reminder(&key)?;
+
+    update_state(&app_handle, set_reminder_state_in_db(&reminder.key, None), "dismiss")?;
+    Ok("Reminder dismissed".to_string())
+}
+
+/// Brings back a snoozed or dismissed reminder
+#[command]
+pub fn restore_reminder(app_handle: AppHandle, key: String) -> Result<String, String> {
+    update_state(&app_handle, clear_reminder_state_in_db(&key), "restore")?;
+    Ok("Reminder restored".to_string())
+}
\ No newline at end of file
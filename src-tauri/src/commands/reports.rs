@@ -0,0 +1,359 @@
+use tauri::command;
+use chrono::{Local, Months, NaiveDate};
+use crate::database::models::{RetirementForecast, Staff};
+use crate::database::reports::build_retirement_forecast;
+use crate::database::operations::get_staff_by_id_from_db;
+use crate::database::postings::get_postings_from_db;
+use crate::database::dependents::get_dependents_from_db;
+use crate::database::qualifications::{get_qualifications_from_db, get_training_courses_from_db};
+use crate::database::notes::get_notes_from_db;
+use crate::database::offices::get_letterhead_from_db;
+use crate::commands::notes::verify_notes_passphrase;
+use crate::utils::export::{write_csv, write_pdf_report, PdfTable};
+
+/// Resolves the forecast window: a whole fiscal year (January to December)
+/// when given, otherwise the next `months` months (12 by default)
+fn forecast_window(months: Option<u32>, fiscal_year: Option<i32>) -> Result<(NaiveDate, NaiveDate), String> {
+    if let Some(year) = fiscal_year {
+        let from = NaiveDate::from_ymd_opt(year, 1, 1).ok_or("Invalid fiscal year")?;
+        let to = NaiveDate::from_ymd_opt(year, 12, 31).ok_or("Invalid fiscal year")?;
+        return Ok((from, to));
+    }
+
+    let from = Local::now().date_naive();
+    let to = from
+        .checked_add_months(Months::new(months.unwrap_or(12)))
+        .ok_or("Invalid number of months")?;
+    Ok((from, to))
+}
+
+#[command]
+pub fn get_retirement_forecast(
+    months: Option<u32>,
+    fiscal_year: Option<i32>,
+    office_id: Option<i32>,
+) -> Result<RetirementForecast, String> {
+    let (from, to) = forecast_window(months, fiscal_year)?;
+    match build_retirement_forecast(from, to, office_id) {
+        Ok(forecast) => Ok(forecast),
+        Err(e) => Err(format!("Failed to get retirement forecast: {}", e)),
+    }
+}
+
+#[command]
+pub fn export_retirement_forecast(
+    months: Option<u32>,
+    fiscal_year: Option<i32>,
+    office_id: Option<i32>,
+    format: String,
+    output_path: String,
+) -> Result<String, String> {
+    let (from, to) = forecast_window(months, fiscal_year)?;
+    let forecast = build_retirement_forecast(from, to, office_id)
+        .map_err(|e| format!("Failed to get retirement forecast: {}", e))?;
+
+    let result = match format.as_str() {
+        "csv" => {
+            let rows: Vec<Vec<String>> = forecast.groups.iter()
+                .flat_map(|group| group.staff.iter().map(move |s| vec![
+                    group.year.to_string(),
+                    s.designation.clone(),
+                    s.appointment_number.clone(),
+                    s.full_name.clone(),
+                    s.salary_code.clone(),
+                    s.date_of_retirement.clone(),
+                    format!("{:.2}", s.basic_salary),
+                ]))
+                .collect();
+
+            write_csv(
+                &output_path,
+                &["Year", "Designation", "Appointment Number", "Full Name", "Salary Code",
+                  "Date of Retirement", "Basic Salary"],
+                &rows,
+            )
+        }
+        "pdf" => {
+            let letterhead = get_letterhead_from_db(office_id)
+                .map_err(|e| format!("Failed to get office letterhead: {}", e))?;
+
+            let tables: Vec<PdfTable> = forecast.groups.iter()
+                .map(|group| {
+                    let mut rows: Vec<Vec<String>> = group.staff.iter()
+                        .map(|s| vec![
+                            s.appointment_number.clone(),
+                            s.full_name.clone(),
+                            s.salary_code.clone(),
+                            s.date_of_retirement.clone(),
+                            format!("{:.2}", s.basic_salary),
+                        ])
+                        .collect();
+                    rows.push(vec![
+                        String::new(),
+                        format!("{} retiring", group.count),
+                        String::new(),
+                        "Total".to_string(),
+                        format!("{:.2}", group.salary_bill),
+                    ]);
+
+                    PdfTable {
+                        heading: Some(format!("{} - {}", group.year, group.designation)),
+                        headers: ["Appointment No", "Full Name", "Salary Code", "Retirement Date", "Basic Salary"]
+                            .iter().map(|h| h.to_string()).collect(),
+                        widths: vec![32.0, 68.0, 22.0, 30.0, 28.0],
+                        rows,
+                    }
+                })
+                .collect();
+
+            write_pdf_report(
+                &output_path,
+                &letterhead,
+                "Retirement Forecast",
+                &format!(
+                    "{} to {} - {} retiring, monthly salary bill {:.2}",
+                    forecast.from_date, forecast.to_date,
+                    forecast.total_retiring, forecast.total_salary_bill
+                ),
+                &tables,
+            )
+        }
+        _ => return Err(format!("Unsupported export format: {}", format)),
+    };
+
+    match result {
+        Ok(_) => Ok(format!("Retirement forecast exported to {}", output_path)),
+        Err(e) => Err(format!("Failed to export retirement forecast: {}", e)),
+    }
+}
+
+fn details_table(heading: &str, fields: Vec<(&str, String)>) -> PdfTable {
+    PdfTable {
+        heading: Some(heading.to_string()),
+        headers: Vec::new(),
+        widths: vec![60.0, 120.0],
+        rows: fields.into_iter().map(|(label, value)| vec![label.to_string(), value]).collect(),
+    }
+}
+
+/// Sections of a staff member's personal file, one table per section. The
+/// restricted notes log is only included when asked for.
+fn personal_file_tables(staff: &Staff, include_notes: bool) -> Result<Vec<PdfTable>, String> {
+    let staff_id = staff.id.unwrap_or_default();
+
+    let address = [Some(&staff.address_line1), staff.address_line2.as_ref(), staff.address_line3.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter(|line| !line.trim().is_empty())
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let mut tables = vec![
+        details_table("Personal Details", vec![
+            ("Appointment Number", staff.appointment_number.clone()),
+            ("Full Name", staff.full_name.clone()),
+            ("Gender", staff.gender.clone()),
+            ("Date of Birth", staff.date_of_birth.clone()),
+            ("NIC Number", staff.nic_number.to_string()),
+            ("Marital Status", staff.marital_status.clone()),
+            ("Address", address),
+            ("Contact Number", staff.contact_number.clone()),
+            ("Email", staff.email.clone().unwrap_or_default()),
+        ]),
+        details_table("Employment Details", vec![
+            ("Designation", staff.designation.clone()),
+            ("Date of First Appointment", staff.date_of_first_appointment.clone()),
+            ("Date of Retirement", staff.date_of_retirement.clone()),
+            ("Increment Date", staff.increment_date.clone()),
+            ("Probation End Date", staff.probation_end_date.clone().unwrap_or_default()),
+            ("Date of Confirmation", match (&staff.confirmation_date, &staff.confirmation_reference) {
+                (Some(date), Some(reference)) => format!("{} ({})", date, reference),
+                (Some(date), None) => date.clone(),
+                _ => String::new(),
+            }),
+            ("Salary Code", staff.salary_code.clone()),
+            ("Basic Salary", format!("{:.2}", staff.basic_salary)),
+            ("Increment Amount", format!("{:.2}", staff.increment_amount)),
+        ]),
+    ];
+
+    let postings = get_postings_from_db(staff_id).map_err(|e| format!("Failed to get postings: {}", e))?;
+    tables.push(PdfTable {
+        heading: Some("Postings".to_string()),
+        headers: ["Station", "From", "To", "Type", "Order Reference"].iter().map(|h| h.to_string()).collect(),
+        widths: vec![60.0, 25.0, 25.0, 30.0, 40.0],
+        rows: postings.iter()
+            .map(|p| vec![
+                p.station.clone(),
+                p.from_date.clone(),
+                p.to_date.clone().unwrap_or_else(|| "To date".to_string()),
+                p.transfer_type.clone(),
+                p.order_reference.clone().unwrap_or_default(),
+            ])
+            .collect(),
+    });
+
+    let qualifications = get_qualifications_from_db(staff_id)
+        .map_err(|e| format!("Failed to get qualifications: {}", e))?;
+    tables.push(PdfTable {
+        heading: Some("Qualifications".to_string()),
+        headers: ["Qualification", "Type", "Institution", "Awarded", "Result"].iter().map(|h| h.to_string()).collect(),
+        widths: vec![60.0, 25.0, 50.0, 25.0, 20.0],
+        rows: qualifications.iter()
+            .map(|q| vec![
+                q.title.clone(),
+                q.category.clone(),
+                q.institution.clone(),
+                q.awarded_date.clone().unwrap_or_default(),
+                q.result.clone().unwrap_or_default(),
+            ])
+            .collect(),
+    });
+
+    let courses = get_training_courses_from_db(staff_id)
+        .map_err(|e| format!("Failed to get training courses: {}", e))?;
+    tables.push(PdfTable {
+        heading: Some("Training".to_string()),
+        headers: ["Course", "Institution", "From", "To", "Result"].iter().map(|h| h.to_string()).collect(),
+        widths: vec![60.0, 50.0, 25.0, 25.0, 20.0],
+        rows: courses.iter()
+            .map(|c| vec![
+                c.course_name.clone(),
+                c.institution.clone(),
+                c.start_date.clone(),
+                c.end_date.clone().unwrap_or_default(),
+                match c.result.clone() {
+                    Some(result) => result,
+                    None if c.completed => "Completed".to_string(),
+                    None => "In progress".to_string(),
+                },
+            ])
+            .collect(),
+    });
+
+    let dependents = get_dependents_from_db(staff_id).map_err(|e| format!("Failed to get dependents: {}", e))?;
+    tables.push(PdfTable {
+        heading: Some("Dependents".to_string()),
+        headers: ["Relationship", "Name", "Date of Birth", "NIC Number", "Nominee"].iter().map(|h| h.to_string()).collect(),
+        widths: vec![25.0, 60.0, 25.0, 32.0, 38.0],
+        rows: dependents.iter()
+            .map(|d| {
+                let nominee: Vec<&str> = [(d.wop_nominee, "W&OP"), (d.gratuity_nominee, "Gratuity")]
+                    .into_iter()
+                    .filter(|(flag, _)| *flag)
+                    .map(|(_, label)| label)
+                    .collect();
+
+                vec![
+                    d.relationship.clone(),
+                    d.full_name.clone(),
+                    d.date_of_birth.clone().unwrap_or_default(),
+                    d.nic_number.clone().unwrap_or_default(),
+                    nominee.join(", "),
+                ]
+            })
+            .collect(),
+    });
+
+    if include_notes {
+        let notes = get_notes_from_db(staff_id).map_err(|e| format!("Failed to get notes: {}", e))?;
+        tables.push(PdfTable {
+            heading: Some("Notes and Disciplinary Record".to_string()),
+            headers: ["Date", "Category", "Reference", "Details", "Outcome"].iter().map(|h| h.to_string()).collect(),
+            widths: vec![22.0, 30.0, 28.0, 60.0, 40.0],
+            rows: notes.iter()
+                .map(|n| vec![
+                    n.note_date.clone(),
+                    n.category.replace('_', " "),
+                    n.reference_number.clone().unwrap_or_default(),
+                    n.description.clone(),
+                    n.outcome.clone().unwrap_or_default(),
+                ])
+                .collect(),
+        });
+    }
+
+    Ok(tables)
+}
+
+/// Exports personal files. The notes and disciplinary record is added when
+/// `notes_passphrase` is given and correct.
+#[command]
+pub fn export_personal_file(
+    staff_ids: Vec<i32>,
+    format: String,
+    output_path: String,
+    notes_passphrase: Option<String>,
+) -> Result<String, String> {
+    if let Some(ref passphrase) = notes_passphrase {
+        verify_notes_passphrase(passphrase)?;
+    }
+
+    let mut files = Vec::new();
+    for id in &staff_ids {
+        let staff = get_staff_by_id_from_db(*id)
+            .map_err(|e| format!("Failed to get staff: {}", e))?
+            .ok_or_else(|| format!("Staff member {} not found", id))?;
+        let tables = personal_file_tables(&staff, notes_passphrase.is_some())?;
+        files.push((staff, tables));
+    }
+
+    if files.is_empty() {
+        return Err("No staff selected for export".to_string());
+    }
+
+    let result = match format.as_str() {
+        // One line per field or list entry, with the section it belongs to
+        "csv" => {
+            let rows: Vec<Vec<String>> = files.iter()
+                .flat_map(|(staff, tables)| tables.iter().flat_map(move |table| {
+                    table.rows.iter().map(move |row| vec![
+                        staff.appointment_number.clone(),
+                        staff.full_name.clone(),
+                        table.heading.clone().unwrap_or_default(),
+                        row.first().cloned().unwrap_or_default(),
+                        row.iter().skip(1).cloned().collect::<Vec<String>>().join(" | "),
+                    ])
+                }))
+                .collect();
+
+            write_csv(&output_path, &["Appointment Number", "Full Name", "Section", "Item", "Details"], &rows)
+        }
+        "pdf" => {
+            let subtitle = match files.as_slice() {
+                [(staff, _)] => format!("{} - {}", staff.appointment_number, staff.full_name),
+                _ => format!("{} staff members", files.len()),
+            };
+
+            // Files from a single office go out under that office's letterhead
+            let office_id = match files.first() {
+                Some((first, _)) if files.iter().all(|(staff, _)| staff.office_id == first.office_id) => first.office_id,
+                _ => None,
+            };
+            let letterhead = get_letterhead_from_db(office_id)
+                .map_err(|e| format!("Failed to get office letterhead: {}", e))?;
+
+            // Label each section with its owner when several files are combined
+            let combined = files.len() > 1;
+            let tables: Vec<PdfTable> = files.into_iter()
+                .flat_map(|(staff, tables)| tables.into_iter().map(move |mut table| {
+                    if combined {
+                        table.heading = table.heading.map(|heading| {
+                            format!("{} - {}: {}", staff.appointment_number, staff.full_name, heading)
+                        });
+                    }
+                    table
+                }))
+                .collect();
+
+            write_pdf_report(&output_path, &letterhead, "Personal File", &subtitle, &tables)
+        }
+        _ => return Err(format!("Unsupported export format: {}", format)),
+    };
+
+    match result {
+        Ok(_) => Ok(format!("Personal file exported to {}", output_path)),
+        Err(e) => Err(format!("Failed to export personal file: {}", e)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use tauri::command;
+use crate::database::models::{
+    Staff, SalaryHistoryEntry, SalaryScale, SalaryScaleSegment, SalaryScaleNotation, SalaryStep
+};
+use crate::database::salary::{
+    SALARY_CHANGE_REASONS, record_salary_change_in_db, get_salary_history_from_db,
+    correct_salary_entry_in_db, save_salary_scales_in_db, get_salary_scales_from_db,
+    get_current_salary_scale_from_db
+};
+use crate::utils::date_utils::parse_date;
+use crate::utils::salary_scale::{parse_scale_notation, scale_maximum, locate_on_scale};
+
+fn validate_salary_entry(entry: &SalaryHistoryEntry) -> Result<(), String> {
+    if parse_date(&entry.effective_date).is_none() {
+        return Err(format!("Invalid effective date: {}", entry.effective_date));
+    }
+
+    if !SALARY_CHANGE_REASONS.contains(&entry.reason.as_str()) {
+        return Err(format!("Invalid reason for salary change: {}", entry.reason));
+    }
+
+    if entry.basic_salary < 0.0 || entry.increment_amount < 0.0 {
+        return Err("Salary amounts cannot be negative".to_string());
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn record_salary_change(entry: SalaryHistoryEntry) -> Result<String, String> {
+    validate_salary_entry(&entry)?;
+    match record_salary_change_in_db(&entry) {
+        Ok(id) => Ok(format!("Salary change recorded with ID: {}", id)),
+        Err(e) => Err(format!("Failed to record salary change: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_salary_history(staff_id: i32) -> Result<Vec<SalaryHistoryEntry>, String> {
+    match get_salary_history_from_db(staff_id) {
+        Ok(entries) => Ok(entries),
+        Err(e) => Err(format!("Failed to get salary history: {}", e)),
+    }
+}
+
+#[command]
+pub fn correct_salary_entry(entry: SalaryHistoryEntry) -> Result<String, String> {
+    if entry.id.is_none() {
+        return Err("Salary history entry ID is required".to_string());
+    }
+    validate_salary_entry(&entry)?;
+    match correct_salary_entry_in_db(&entry) {
+        Ok(_) => Ok("Salary history entry corrected successfully".to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Err(format!(
+            "Salary history entry {} not found for staff member {}",
+            entry.id.unwrap_or_default(),
+            entry.staff_id
+        )),
+        Err(e) => Err(format!("Failed to correct salary history entry: {}", e)),
+    }
+}
+
+/// Checks the staff member's basic salary against the scale in force for their
+/// salary code and fills in the increment amount when it was left at zero.
+/// Salary codes without a loaded scale are not checked.
+pub fn apply_salary_scale(staff: &mut Staff) -> Result<(), String> {
+    let scale = get_current_salary_scale_from_db(&staff.salary_code)
+        .map_err(|e| format!("Failed to get salary scale: {}", e))?;
+
+    let scale = match scale {
+        Some(scale) => scale,
+        None => return Ok(()),
+    };
+
+    let position = locate_on_scale(scale.initial_salary, &scale.segment_tuples(), staff.basic_salary)
+        .ok_or_else(|| format!(
+            "Basic salary {:.2} is not a step on the {} salary scale",
+            staff.basic_salary, staff.salary_code
+        ))?;
+
+    if staff.increment_amount == 0.0 {
+        staff.increment_amount = position.increment_amount;
+    } else if (staff.increment_amount - position.increment_amount).abs() > 0.005 {
+        return Err(format!(
+            "Increment amount for step {} of the {} salary scale should be {:.2}",
+            position.step, staff.salary_code, position.increment_amount
+        ));
+    }
+
+    Ok(())
+}
+
+#[command]
+pub fn import_salary_scales(
+    circular_reference: String,
+    effective_from: String,
+    scales: Vec<SalaryScaleNotation>,
+) -> Result<String, String> {
+    if parse_date(&effective_from).is_none() {
+        return Err(format!("Invalid effective date: {}", effective_from));
+    }
+
+    let mut parsed = Vec::new();
+    for scale in scales {
+        let (initial_salary, segments) = parse_scale_notation(&scale.notation)
+            .map_err(|e| format!("{}: {}", scale.salary_code, e))?;
+
+        parsed.push(SalaryScale {
+            id: None,
+            salary_code: scale.salary_code,
+            initial_salary,
+            maximum_salary: scale_maximum(initial_salary, &segments),
+            segments: segments.into_iter()
+                .map(|(step_count, increment_amount)| SalaryScaleSegment { step_count, increment_amount })
+                .collect(),
+            circular_reference: Some(circular_reference.clone()),
+            effective_from: effective_from.clone(),
+            created_at: None,
+        });
+    }
+
+    match save_salary_scales_in_db(&parsed) {
+        Ok(count) => Ok(format!("{} salary scales loaded from {}", count, circular_reference)),
+        Err(e) => Err(format!("Failed to load salary scales: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_salary_scales() -> Result<Vec<SalaryScale>, String> {
+    match get_salary_scales_from_db() {
+        Ok(scales) => Ok(scales),
+        Err(e) => Err(format!("Failed to get salary scales: {}", e)),
+    }
+}
+
+#[command]
+pub fn get_salary_step(salary_code: String, basic_salary: f64) -> Result<SalaryStep, String> {
+    let scale = get_current_salary_scale_from_db(&salary_code)
+        .map_err(|e| format!("Failed to get salary scale: {}", e))?
+        .ok_or_else(|| format!("No salary scale loaded for {}", salary_code))?;
+
+    let position = locate_on_scale(scale.initial_salary, &scale.segment_tuples(), basic_salary)
+        .ok_or_else(|| format!("Basic salary {:.2} is not a step on the {} salary scale", basic_salary, salary_code))?;
+
+    Ok(SalaryStep {
+        salary_code,
+        step: position.step,
+        basic_salary,
+        increment_amount: position.increment_amount,
+        next_salary: position.next_salary,
+        maximum_salary: scale.maximum_salary,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use tauri::command;
+use chrono::{Local, Months, NaiveDate};
+use crate::database::models::{ServiceBreak, ServiceLength, ServiceMilestone};
+use crate::database::service::{
+    get_service_breaks_from_db, insert_service_break_in_db, delete_service_break_from_db,
+    get_service_length_from_db, get_service_milestones_from_db
+};
+use crate::database::pension::get_pension_rules_from_db;
+use crate::utils::date_utils::{parse_date, format_date};
+
+fn optional_date(date: Option<String>, label: &str) -> Result<Option<NaiveDate>, String> {
+    match date.as_deref().map(str::trim) {
+        Some(date) if !date.is_empty() => parse_date(date)
+            .map(Some)
+            .ok_or_else(|| format!("Invalid {}: {}", label, date)),
+        _ => Ok(None),
+    }
+}
+
+#[command]
+pub fn get_service_length(staff_id: i32, as_of: Option<String>) -> Result<ServiceLength, String> {
+    let as_of = optional_date(as_of, "date")?.unwrap_or_else(|| Local::now().date_naive());
+
+    get_service_length_from_db(staff_id, as_of)
+        .map_err(|e| format!("Failed to get service length: {}", e))?
+        .ok_or_else(|| "Service length cannot be calculated: staff member not found or appointment date is invalid".to_string())
+}
+
+#[command]
+pub fn get_service_breaks(staff_id: i32) -> Result<Vec<ServiceBreak>, String> {
+    match get_service_breaks_from_db(staff_id) {
+        Ok(breaks) => Ok(breaks),
+        Err(e) => Err(format!("Failed to get service breaks: {}", e)),
+    }
+}
+
+#[command]
+pub fn add_service_break(mut service_break: ServiceBreak) -> Result<i64, String> {
+    let from = parse_date(&service_break.from_date)
+        .ok_or_else(|| format!("Invalid break start date: {}", service_break.from_date))?;
+    let to = parse_date(&service_break.to_date)
+        .ok_or_else(|| format!("Invalid break end date: {}", service_break.to_date))?;
+
+    if to < from {
+        return Err("Break end date cannot be before the start date".to_string());
+    }
+
+    if service_break.reason.trim().is_empty() {
+        return Err("Reason for the break is required".to_string());
+    }
+
+    service_break.from_date = format_date(from);
+    service_break.to_date = format_date(to);
+
+    match insert_service_break_in_db(&service_break) {
+        Ok(id) => Ok(id),
+        Err(e) => Err(format!("Failed to add service break: {}", e)),
+    }
+}
+
+#[command]
+pub fn delete_service_break(id: i32) -> Result<String, String> {
+    match delete_service_break_from_db(id) {
+        Ok(_) => Ok("Service break deleted successfully".to_string()),
+        Err(e) => Err(format!("Failed to delete service break: {}", e)),
+    }
+}
+
+/// Service milestones reached between the two dates, by default over the
+/// next three months
+#[command]
+pub fn get_service_milestones(from_date: Option<String>, to_date: Option<String>) -> Result<Vec<ServiceMilestone>, String> {
+    let from = optional_date(from_date, "start date")?.unwrap_or_else(|| Local::now().date_naive());
+    let to = match optional_date(to_date, "end date")? {
+        Some(to) => to,
+        None => from.checked_add_months(Months::new(3)).ok_or("Invalid start date")?,
+    };
+
+    if to < from {
+        return Err("End date cannot be before the start date".to_string());
+    }
+
+    let rules = get_pension_rules_from_db().map_err(|e| format!("Failed to get pension rules: {}", e))?;
+
+    match get_service_milestones_from_db(from, to, rules.minimum_service_years) {
+        Ok(milestones) => Ok(milestones),
+        Err(e) => Err(format!("Failed to get service milestones: {}", e)),
+    }
+}
\ No newline at end of file
@@ -1,13 +1,24 @@
 use tauri::command;
-use crate::database::models::{Staff, StaffSearch, StaffCount};
+use crate::database::models::{Staff, StaffSearch, StaffCount, WorkforceStatistics};
 use crate::database::operations::{
     insert_staff, get_all_staff_from_db, get_staff_by_id_from_db,
     update_staff_in_db, delete_staff_from_db, search_staff_in_db,
-    get_staff_statistics
+    get_staff_statistics, get_workforce_statistics
 };
+use crate::commands::salary::apply_salary_scale;
+use crate::commands::designations::resolve_designation;
+use crate::commands::probation::apply_probation;
+use crate::commands::offices::check_office;
+use crate::commands::nic::{check_nic, check_unique_nic, nic_warning};
 
 #[command]
-pub fn add_staff(staff: Staff) -> Result<String, String> {
+pub fn add_staff(mut staff: Staff) -> Result<String, String> {
+    resolve_designation(&mut staff)?;
+    apply_salary_scale(&mut staff)?;
+    apply_probation(&mut staff)?;
+    check_office(&staff)?;
+    check_nic(&staff)?;
+    check_unique_nic(&staff)?;
     match insert_staff(&staff) {
         Ok(id) => Ok(format!("Staff added successfully with ID: {}", id)),
         Err(e) => Err(format!("Failed to add staff: {}", e)),
@@ -31,10 +42,19 @@ pub fn get_staff_by_id(id: i32) -> Result<Option<Staff>, String> {
 }
 
 #[command]
-pub fn update_staff(staff: Staff) -> Result<String, String> {
-    match update_staff_in_db(&staff) {
-        Ok(_) => Ok("Staff updated successfully".to_string()),
-        Err(e) => Err(format!("Failed to update staff: {}", e)),
+pub fn update_staff(mut staff: Staff) -> Result<String, String> {
+    resolve_designation(&mut staff)?;
+    apply_salary_scale(&mut staff)?;
+    apply_probation(&mut staff)?;
+    check_office(&staff)?;
+    check_unique_nic(&staff)?;
+
+    // Existing records that disagree with their NIC can still be edited; the
+    // disagreement is reported back and listed in the NIC mismatch report
+    match (update_staff_in_db(&staff), nic_warning(&staff)) {
+        (Ok(_), Some(warning)) => Ok(format!("Staff updated successfully. Please check the NIC number: {}", warning)),
+        (Ok(_), None) => Ok("Staff updated successfully".to_string()),
+        (Err(e), _) => Err(format!("Failed to update staff: {}", e)),
     }
 }
 
@@ -55,13 +75,21 @@ pub fn search_staff(search: StaffSearch) -> Result<Vec<Staff>, String> {
 }
 
 #[command]
-pub fn get_staff_count() -> Result<StaffCount, String> {
-    match get_staff_statistics() {
+pub fn get_staff_count(office_id: Option<i32>) -> Result<StaffCount, String> {
+    match get_staff_statistics(office_id) {
         Ok(count) => Ok(count),
         Err(e) => Err(format!("Failed to get staff count: {}", e)),
     }
 }
 
+#[command]
+pub fn get_workforce_stats(office_id: Option<i32>) -> Result<WorkforceStatistics, String> {
+    match get_workforce_statistics(office_id) {
+        Ok(stats) => Ok(stats),
+        Err(e) => Err(format!("Failed to get workforce statistics: {}", e)),
+    }
+}
+
 #[command]
 pub fn export_staff_to_pdf(staff_ids: Vec<i32>, template_type: String) -> Result<String, String> {
     // This is a placeholder for PDF export functionality
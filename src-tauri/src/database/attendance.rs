@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, BTreeSet};
+use rusqlite::{params, Connection, Result};
+use chrono::{Datelike, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use crate::database::models::{AttendanceMapping, OfficeHours, Holiday, DailyAttendance, AttendanceSummary};
+use crate::database::operations::get_connection;
+use crate::utils::attendance::{Punch, PUNCH_FORMAT, parse_time, late_minutes};
+use crate::utils::date_utils::{parse_date, format_date};
+
+pub fn create_attendance_tables(conn: &Connection) -> Result<()> {
+    // Enrolment numbers on the fingerprint terminal do not match appointment
+    // numbers, so each device user is mapped to a staff member
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS attendance_user_map (
+            device_user_id TEXT PRIMARY KEY,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS attendance_punches (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            device_user_id TEXT NOT NULL,
+            punch_time TEXT NOT NULL,
+            source_file TEXT,
+            imported_at TEXT NOT NULL,
+            UNIQUE(device_user_id, punch_time)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS office_hours (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            start_time TEXT NOT NULL,
+            end_time TEXT NOT NULL,
+            grace_minutes INTEGER NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "INSERT OR IGNORE INTO office_hours (id, start_time, end_time, grace_minutes)
+         VALUES (1, '08:30', '16:15', 15)",
+        [],
+    )?;
+
+    // Public, bank and Poya holidays, entered each year from the gazette
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS holidays (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            holiday_date TEXT NOT NULL UNIQUE,
+            name TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Stores punches, ignoring any already imported. Returns the number of new
+/// punches and the number of duplicates.
+pub fn save_punches_in_db(punches: &[Punch], source_file: &str) -> Result<(usize, usize)> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let mut imported = 0;
+    for punch in punches {
+        imported += tx.execute(
+            "INSERT OR IGNORE INTO attendance_punches (device_user_id, punch_time, source_file, imported_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                punch.device_user_id,
+                punch.time.format(PUNCH_FORMAT).to_string(),
+                source_file,
+                now
+            ],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok((imported, punches.len() - imported))
+}
+
+/// Device user IDs that have punches but no staff mapping
+pub fn get_unmapped_device_users_from_db() -> Result<Vec<String>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT p.device_user_id FROM attendance_punches p
+         LEFT JOIN attendance_user_map m ON m.device_user_id = p.device_user_id
+         WHERE m.staff_id IS NULL
+         ORDER BY CAST(p.device_user_id AS INTEGER), p.device_user_id"
+    )?;
+
+    let id_iter = stmt.query_map([], |row| row.get(0))?;
+
+    let mut ids = Vec::new();
+    for id in id_iter {
+        ids.push(id?);
+    }
+
+    Ok(ids)
+}
+
+pub fn get_attendance_mappings_from_db() -> Result<Vec<AttendanceMapping>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT m.device_user_id, m.staff_id, s.appointment_number, s.full_name
+         FROM attendance_user_map m
+         LEFT JOIN staff s ON s.id = m.staff_id
+         ORDER BY CAST(m.device_user_id AS INTEGER), m.device_user_id"
+    )?;
+
+    let mapping_iter = stmt.query_map([], |row| {
+        Ok(AttendanceMapping {
+            device_user_id: row.get(0)?,
+            staff_id: row.get(1)?,
+            appointment_number: row.get(2)?,
+            full_name: row.get(3)?,
+        })
+    })?;
+
+    let mut mappings = Vec::new();
+    for mapping in mapping_iter {
+        mappings.push(mapping?);
+    }
+
+    Ok(mappings)
+}
+
+pub fn set_attendance_mapping_in_db(device_user_id: &str, staff_id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "INSERT INTO attendance_user_map (device_user_id, staff_id) VALUES (?1, ?2)
+         ON CONFLICT(device_user_id) DO UPDATE SET staff_id = excluded.staff_id",
+        params![device_user_id, staff_id],
+    )?;
+    Ok(())
+}
+
+pub fn delete_attendance_mapping_from_db(device_user_id: &str) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "DELETE FROM attendance_user_map WHERE device_user_id = ?1",
+        params![device_user_id],
+    )?;
+    Ok(())
+}
+
+fn office_hours(conn: &Connection) -> Result<OfficeHours> {
+    conn.query_row(
+        "SELECT start_time, end_time, grace_minutes FROM office_hours WHERE id = 1",
+        [],
+        |row| {
+            Ok(OfficeHours {
+                start_time: row.get(0)?,
+                end_time: row.get(1)?,
+                grace_minutes: row.get(2)?,
+            })
+        },
+    )
+}
+
+pub fn get_office_hours_from_db() -> Result<OfficeHours> {
+    let conn = get_connection()?;
+    office_hours(&conn)
+}
+
+pub fn update_office_hours_in_db(hours: &OfficeHours) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "UPDATE office_hours SET start_time = ?1, end_time = ?2, grace_minutes = ?3 WHERE id = 1",
+        params![hours.start_time, hours.end_time, hours.grace_minutes],
+    )?;
+    Ok(())
+}
+
+fn holidays(conn: &Connection) -> Result<Vec<Holiday>> {
+    let mut stmt = conn.prepare("SELECT id, holiday_date, name, created_at FROM holidays")?;
+
+    let holiday_iter = stmt.query_map([], |row| {
+        Ok(Holiday {
+            id: Some(row.get(0)?),
+            holiday_date: row.get(1)?,
+            name: row.get(2)?,
+            created_at: Some(row.get(3)?),
+        })
+    })?;
+
+    let mut holidays = Vec::new();
+    for holiday in holiday_iter {
+        holidays.push(holiday?);
+    }
+
+    // Dates are stored as dd-MM-yyyy, so order chronologically here
+    holidays.sort_by_key(|holiday| parse_date(&holiday.holiday_date));
+    Ok(holidays)
+}
+
+/// Holidays in the given year, or all of them
+pub fn get_holidays_from_db(year: Option<i32>) -> Result<Vec<Holiday>> {
+    let conn = get_connection()?;
+    Ok(holidays(&conn)?
+        .into_iter()
+        .filter(|holiday| year.is_none_or(|year| {
+            parse_date(&holiday.holiday_date).is_some_and(|date| date.year() == year)
+        }))
+        .collect())
+}
+
+pub fn add_holiday_in_db(holiday: &Holiday) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO holidays (holiday_date, name, created_at) VALUES (?1, ?2, ?3)",
+        params![holiday.holiday_date, holiday.name, now],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn delete_holiday_from_db(id: i32) -> Result<usize> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM holidays WHERE id = ?1", params![id])
+}
+
+/// Punch times of a staff member in the given month, grouped by day
+fn punches_by_day(conn: &Connection, staff_id: i32, first: NaiveDate, next: NaiveDate) -> Result<BTreeMap<NaiveDate, Vec<NaiveTime>>> {
+    let mut stmt = conn.prepare(
+        "SELECT p.punch_time FROM attendance_punches p
+         JOIN attendance_user_map m ON m.device_user_id = p.device_user_id
+         WHERE m.staff_id = ?1 AND p.punch_time >= ?2 AND p.punch_time < ?3
+         ORDER BY p.punch_time"
+    )?;
+
+    let time_iter = stmt.query_map(
+        params![staff_id, first.format("%Y-%m-%d").to_string(), next.format("%Y-%m-%d").to_string()],
+        |row| row.get::<_, String>(0),
+    )?;
+
+    let mut days: BTreeMap<NaiveDate, Vec<NaiveTime>> = BTreeMap::new();
+    for time in time_iter {
+        if let Ok(time) = NaiveDateTime::parse_from_str(&time?, PUNCH_FORMAT) {
+            days.entry(time.date()).or_default().push(time.time());
+        }
+    }
+
+    Ok(days)
+}
+
+/// Periods of approved leave for a staff member
+fn approved_leave(conn: &Connection, staff_id: i32) -> Result<Vec<(NaiveDate, NaiveDate)>> {
+    let mut stmt = conn.prepare(
+        "SELECT from_date, to_date FROM leave_applications WHERE staff_id = ?1 AND status = 'approved'"
+    )?;
+
+    let period_iter = stmt.query_map(params![staff_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    let mut periods = Vec::new();
+    for period in period_iter {
+        let (from, to) = period?;
+        if let (Some(from), Some(to)) = (parse_date(&from), parse_date(&to)) {
+            periods.push((from, to));
+        }
+    }
+
+    Ok(periods)
+}
+
+/// Attendance for each working day of the month from the first appointment
+/// up to today. Weekends and holidays are skipped; a working day with no
+/// punches and no approved leave is an absence, except today, which stays
+/// pending until its punches are imported.
+fn daily_attendance(conn: &Connection, staff_id: i32, year: i32, month: u32) -> Result<Vec<DailyAttendance>> {
+    let hours = office_hours(conn)?;
+    let start = parse_time(&hours.start_time).unwrap_or(NaiveTime::MIN);
+    let end = parse_time(&hours.end_time).unwrap_or(NaiveTime::MIN);
+
+    let first = match NaiveDate::from_ymd_opt(year, month, 1) {
+        Some(first) => first,
+        None => return Ok(Vec::new()),
+    };
+    let next = first + Months::new(1);
+    let today = Local::now().date_naive();
+
+    let appointed: String = conn.query_row(
+        "SELECT date_of_first_appointment FROM staff WHERE id = ?1",
+        params![staff_id],
+        |row| row.get(0),
+    )?;
+    let appointed = parse_date(&appointed).unwrap_or(first);
+
+    let holidays: BTreeSet<NaiveDate> = holidays(conn)?
+        .iter()
+        .filter_map(|holiday| parse_date(&holiday.holiday_date))
+        .collect();
+
+    let punches = punches_by_day(conn, staff_id, first, next)?;
+    let leave = approved_leave(conn, staff_id)?;
+
+    let mut days = Vec::new();
+    for date in first.iter_days().take_while(|date| *date < next && *date <= today) {
+        if date < appointed
+            || matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+            || holidays.contains(&date)
+        {
+            continue;
+        }
+
+        let day = match punches.get(&date) {
+            Some(times) => {
+                let check_in = times[0];
+                let check_out = if times.len() > 1 { times.last().copied() } else { None };
+                let late = late_minutes(check_in, start, hours.grace_minutes);
+
+                DailyAttendance {
+                    date: format_date(date),
+                    check_in: Some(check_in.format("%H:%M").to_string()),
+                    check_out: check_out.map(|time| time.format("%H:%M").to_string()),
+                    late_minutes: late,
+                    left_early: check_out.is_some_and(|time| time < end),
+                    status: if late > 0 { "late" } else { "present" }.to_string(),
+                }
+            }
+            None => {
+                let on_leave = leave.iter().any(|(from, to)| *from <= date && date <= *to);
+
+                DailyAttendance {
+                    date: format_date(date),
+                    check_in: None,
+                    check_out: None,
+                    late_minutes: 0,
+                    left_early: false,
+                    status: if on_leave {
+                        "leave"
+                    } else if date == today {
+                        "pending"
+                    } else {
+                        "absent"
+                    }.to_string(),
+                }
+            }
+        };
+        days.push(day);
+    }
+
+    Ok(days)
+}
+
+pub fn get_daily_attendance_from_db(staff_id: i32, year: i32, month: u32) -> Result<Vec<DailyAttendance>> {
+    let conn = get_connection()?;
+    daily_attendance(&conn, staff_id, year, month)
+}
+
+/// Monthly summary for every staff member mapped to a device user
+pub fn get_attendance_summary_from_db(year: i32, month: u32) -> Result<Vec<AttendanceSummary>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT s.id, s.appointment_number, s.full_name, s.designation
+         FROM staff s
+         JOIN attendance_user_map m ON m.staff_id = s.id
+         ORDER BY s.full_name"
+    )?;
+
+    let staff_iter = stmt.query_map([], |row| {
+        Ok((row.get::<_, i32>(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+    })?;
+
+    let mut summaries = Vec::new();
+    for staff in staff_iter {
+        let (staff_id, appointment_number, full_name, designation) = staff?;
+        let days = daily_attendance(&conn, staff_id, year, month)?;
+        let count = |status: &str| days.iter().filter(|day| day.status == status).count() as i32;
+
+        summaries.push(AttendanceSummary {
+            staff_id,
+            appointment_number,
+            full_name,
+            designation,
+            working_days: days.len() as i32 - count("pending"),
+            present_days: count("present") + count("late"),
+            late_days: count("late"),
+            leave_days: count("leave"),
+            absent_days: count("absent"),
+            absent_dates: days.iter()
+                .filter(|day| day.status == "absent")
+                .map(|day| day.date.clone())
+                .collect(),
+        });
+    }
+
+    Ok(summaries)
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+use rusqlite::{params, Connection, Result};
+use chrono::Utc;
+use crate::database::models::Dependent;
+use crate::database::operations::get_connection;
+
+pub const RELATIONSHIPS: [&str; 6] = ["spouse", "son", "daughter", "father", "mother", "other"];
+
+pub fn create_dependent_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS dependents (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            relationship TEXT NOT NULL,
+            full_name TEXT NOT NULL,
+            gender TEXT,
+            date_of_birth TEXT,
+            nic_number TEXT,
+            wop_nominee INTEGER NOT NULL DEFAULT 0,
+            gratuity_nominee INTEGER NOT NULL DEFAULT 0,
+            remarks TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_dependents_staff ON dependents(staff_id)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+pub fn get_dependents_from_db(staff_id: i32) -> Result<Vec<Dependent>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, relationship, full_name, gender, date_of_birth, nic_number,
+         wop_nominee, gratuity_nominee, remarks, created_at, updated_at
+         FROM dependents WHERE staff_id = ?1
+         ORDER BY CASE relationship WHEN 'spouse' THEN 0 WHEN 'son' THEN 1 WHEN 'daughter' THEN 1 ELSE 2 END, id"
+    )?;
+
+    let dependent_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(Dependent {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            relationship: row.get(2)?,
+            full_name: row.get(3)?,
+            gender: row.get(4)?,
+            date_of_birth: row.get(5)?,
+            nic_number: row.get(6)?,
+            wop_nominee: row.get(7)?,
+            gratuity_nominee: row.get(8)?,
+            remarks: row.get(9)?,
+            created_at: Some(row.get(10)?),
+            updated_at: Some(row.get(11)?),
+        })
+    })?;
+
+    let mut dependents = Vec::new();
+    for dependent in dependent_iter {
+        dependents.push(dependent?);
+    }
+
+    Ok(dependents)
+}
+
+pub fn insert_dependent_in_db(dependent: &Dependent) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO dependents (
+            staff_id, relationship, full_name, gender, date_of_birth, nic_number,
+            wop_nominee, gratuity_nominee, remarks, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            dependent.staff_id,
+            dependent.relationship,
+            dependent.full_name,
+            dependent.gender,
+            dependent.date_of_birth,
+            dependent.nic_number,
+            dependent.wop_nominee,
+            dependent.gratuity_nominee,
+            dependent.remarks,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn update_dependent_in_db(dependent: &Dependent) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE dependents SET
+            relationship = ?1, full_name = ?2, gender = ?3, date_of_birth = ?4, nic_number = ?5,
+            wop_nominee = ?6, gratuity_nominee = ?7, remarks = ?8, updated_at = ?9
+         WHERE id = ?10",
+        params![
+            dependent.relationship,
+            dependent.full_name,
+            dependent.gender,
+            dependent.date_of_birth,
+            dependent.nic_number,
+            dependent.wop_nominee,
+            dependent.gratuity_nominee,
+            dependent.remarks,
+            now,
+            dependent.id
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_dependent_from_db(id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM dependents WHERE id = ?1", params![id])?;
+    Ok(())
+}
\ No newline at end of file
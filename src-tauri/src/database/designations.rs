@@ -0,0 +1,258 @@
+use rusqlite::{params, Connection, Result};
+use chrono::Utc;
+use crate::database::models::{Designation, DesignationVacancy, VacancyReport};
+use crate::database::operations::{get_connection, add_column_if_missing};
+use crate::utils::designation::{designation_key, DEFAULT_DESIGNATIONS};
+
+pub fn create_designation_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS designations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            service_grade TEXT,
+            rank_order INTEGER NOT NULL,
+            approved_cadre INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Approved cadre of a designation at one office; designations.approved_cadre
+    // is the cadre across every office
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS office_cadre (
+            office_id INTEGER NOT NULL REFERENCES offices(id),
+            designation_id INTEGER NOT NULL REFERENCES designations(id),
+            approved_cadre INTEGER NOT NULL,
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (office_id, designation_id)
+        )",
+        [],
+    )?;
+
+    add_column_if_missing(conn, "staff", "designation_id", "INTEGER REFERENCES designations(id)")?;
+
+    let count: i32 = conn.query_row("SELECT COUNT(*) FROM designations", [], |row| row.get(0))?;
+    if count == 0 {
+        for (index, name) in DEFAULT_DESIGNATIONS.iter().enumerate() {
+            insert_designation(conn, &Designation {
+                id: None,
+                name: name.to_string(),
+                service_grade: None,
+                rank_order: index as i32 + 1,
+                approved_cadre: 0,
+                created_at: None,
+                updated_at: None,
+            })?;
+        }
+    }
+
+    migrate_staff_designations(conn)
+}
+
+/// Links staff rows that predate the designations table to a designation,
+/// matching the free-text value loosely and creating a designation for any
+/// value that matches none. The text column is rewritten to the canonical name.
+fn migrate_staff_designations(conn: &Connection) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT DISTINCT designation FROM staff WHERE designation_id IS NULL")?;
+    let unlinked = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<String>>>()?;
+
+    for value in unlinked {
+        let designation = match find_designation(conn, &value)? {
+            Some(designation) => designation,
+            None => {
+                let rank_order: i32 = conn.query_row(
+                    "SELECT COALESCE(MAX(rank_order), 0) + 1 FROM designations",
+                    [],
+                    |row| row.get(0),
+                )?;
+                let mut designation = Designation {
+                    id: None,
+                    name: value.trim().to_string(),
+                    service_grade: None,
+                    rank_order,
+                    approved_cadre: 0,
+                    created_at: None,
+                    updated_at: None,
+                };
+                designation.id = Some(insert_designation(conn, &designation)? as i32);
+                designation
+            }
+        };
+
+        conn.execute(
+            "UPDATE staff SET designation_id = ?1, designation = ?2
+             WHERE designation = ?3 AND designation_id IS NULL",
+            params![designation.id, designation.name, value],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn designations(conn: &Connection) -> Result<Vec<Designation>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, service_grade, rank_order, approved_cadre, created_at, updated_at
+         FROM designations ORDER BY rank_order, name"
+    )?;
+
+    let designation_iter = stmt.query_map([], |row| {
+        Ok(Designation {
+            id: Some(row.get(0)?),
+            name: row.get(1)?,
+            service_grade: row.get(2)?,
+            rank_order: row.get(3)?,
+            approved_cadre: row.get(4)?,
+            created_at: Some(row.get(5)?),
+            updated_at: Some(row.get(6)?),
+        })
+    })?;
+
+    let mut designations = Vec::new();
+    for designation in designation_iter {
+        designations.push(designation?);
+    }
+
+    Ok(designations)
+}
+
+/// Finds the designation a free-text value refers to
+pub fn find_designation(conn: &Connection, name: &str) -> Result<Option<Designation>> {
+    let key = designation_key(name);
+    Ok(designations(conn)?.into_iter().find(|d| designation_key(&d.name) == key))
+}
+
+pub fn find_designation_from_db(name: &str) -> Result<Option<Designation>> {
+    let conn = get_connection()?;
+    find_designation(&conn, name)
+}
+
+fn insert_designation(conn: &Connection, designation: &Designation) -> Result<i64> {
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO designations (name, service_grade, rank_order, approved_cadre, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            designation.name,
+            designation.service_grade,
+            designation.rank_order,
+            designation.approved_cadre,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn get_designations_from_db() -> Result<Vec<Designation>> {
+    let conn = get_connection()?;
+    designations(&conn)
+}
+
+pub fn insert_designation_in_db(designation: &Designation) -> Result<i64> {
+    let conn = get_connection()?;
+    insert_designation(&conn, designation)
+}
+
+/// Updates a designation and renames it on every linked staff record
+pub fn update_designation_in_db(designation: &Designation) -> Result<()> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    tx.execute(
+        "UPDATE designations SET
+            name = ?1, service_grade = ?2, rank_order = ?3, approved_cadre = ?4, updated_at = ?5
+         WHERE id = ?6",
+        params![
+            designation.name,
+            designation.service_grade,
+            designation.rank_order,
+            designation.approved_cadre,
+            now,
+            designation.id
+        ],
+    )?;
+
+    tx.execute(
+        "UPDATE staff SET designation = ?1 WHERE designation_id = ?2",
+        params![designation.name, designation.id],
+    )?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+pub fn delete_designation_from_db(id: i32) -> Result<()> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    tx.execute("DELETE FROM office_cadre WHERE designation_id = ?1", params![id])?;
+    tx.execute("DELETE FROM designations WHERE id = ?1", params![id])?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+/// Sets the approved cadre of a designation at one office
+pub fn set_office_cadre_in_db(office_id: i32, designation_id: i32, approved_cadre: i32) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO office_cadre (office_id, designation_id, approved_cadre, updated_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(office_id, designation_id) DO UPDATE SET
+            approved_cadre = excluded.approved_cadre, updated_at = excluded.updated_at",
+        params![office_id, designation_id, approved_cadre, now],
+    )?;
+
+    Ok(())
+}
+
+/// Filled posts against the approved cadre for each designation. For one
+/// office, only its staff are counted against its own cadre, which is zero
+/// until set for that office.
+pub fn get_vacancy_report_from_db(office_id: Option<i32>) -> Result<VacancyReport> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT d.id, d.name, d.service_grade,
+                CASE WHEN ?1 IS NULL THEN d.approved_cadre ELSE COALESCE(oc.approved_cadre, 0) END,
+                COUNT(s.id)
+         FROM designations d
+         LEFT JOIN office_cadre oc ON oc.designation_id = d.id AND oc.office_id = ?1
+         LEFT JOIN staff s ON s.designation_id = d.id AND (?1 IS NULL OR s.office_id = ?1)
+         GROUP BY d.id
+         ORDER BY d.rank_order, d.name"
+    )?;
+
+    let vacancy_iter = stmt.query_map(params![office_id], |row| {
+        let approved_cadre: i32 = row.get(3)?;
+        let filled: i32 = row.get(4)?;
+        Ok(DesignationVacancy {
+            designation_id: row.get(0)?,
+            name: row.get(1)?,
+            service_grade: row.get(2)?,
+            approved_cadre,
+            filled,
+            vacant: approved_cadre - filled,
+        })
+    })?;
+
+    let mut designations = Vec::new();
+    for vacancy in vacancy_iter {
+        designations.push(vacancy?);
+    }
+
+    Ok(VacancyReport {
+        total_approved: designations.iter().map(|d| d.approved_cadre).sum(),
+        total_filled: designations.iter().map(|d| d.filled).sum(),
+        total_vacant: designations.iter().map(|d| d.vacant).sum(),
+        designations,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use chrono::Utc;
+use crate::database::models::StaffDocument;
+use crate::database::operations::{get_connection, add_column_if_missing};
+
+pub const DOCUMENT_TYPES: [&str; 6] = [
+    "appointment_letter",
+    "gazette",
+    "certificate",
+    "transfer_order",
+    "promotion_letter",
+    "other",
+];
+
+pub fn create_document_tables(conn: &Connection) -> Result<()> {
+    // Files live in the app data directory named by content hash; several
+    // rows may share a file, e.g. a gazette naming more than one officer
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS staff_documents (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            document_type TEXT NOT NULL,
+            title TEXT NOT NULL,
+            document_date TEXT,
+            reference_number TEXT,
+            original_file_name TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            file_size INTEGER NOT NULL,
+            created_at TEXT NOT NULL,
+            UNIQUE(staff_id, content_hash)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_staff_documents_hash ON staff_documents(content_hash)",
+        [],
+    )?;
+
+    add_column_if_missing(conn, "staff_documents", "expiry_date", "TEXT")?;
+
+    Ok(())
+}
+
+const DOCUMENT_COLUMNS: &str = "id, staff_id, document_type, title, document_date, reference_number,
+    original_file_name, content_hash, file_size, expiry_date, created_at";
+
+fn document_from_row(row: &rusqlite::Row) -> Result<StaffDocument> {
+    Ok(StaffDocument {
+        id: Some(row.get(0)?),
+        staff_id: row.get(1)?,
+        document_type: row.get(2)?,
+        title: row.get(3)?,
+        document_date: row.get(4)?,
+        reference_number: row.get(5)?,
+        original_file_name: Some(row.get(6)?),
+        content_hash: Some(row.get(7)?),
+        file_size: Some(row.get(8)?),
+        expiry_date: row.get(9)?,
+        created_at: Some(row.get(10)?),
+    })
+}
+
+pub fn get_documents_from_db(staff_id: Option<i32>) -> Result<Vec<StaffDocument>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM staff_documents WHERE ?1 IS NULL OR staff_id = ?1 ORDER BY staff_id, id",
+        DOCUMENT_COLUMNS
+    ))?;
+
+    let document_iter = stmt.query_map(params![staff_id], document_from_row)?;
+
+    let mut documents = Vec::new();
+    for document in document_iter {
+        documents.push(document?);
+    }
+
+    Ok(documents)
+}
+
+pub fn get_document_from_db(id: i32) -> Result<Option<StaffDocument>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        &format!("SELECT {} FROM staff_documents WHERE id = ?1", DOCUMENT_COLUMNS),
+        params![id],
+        document_from_row,
+    ).optional()
+}
+
+/// The document a staff member already has with the given content, if any
+pub fn find_document_by_hash_from_db(staff_id: i32, content_hash: &str) -> Result<Option<StaffDocument>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM staff_documents WHERE staff_id = ?1 AND content_hash = ?2",
+            DOCUMENT_COLUMNS
+        ),
+        params![staff_id, content_hash],
+        document_from_row,
+    ).optional()
+}
+
+pub fn insert_document_in_db(document: &StaffDocument) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO staff_documents (
+            staff_id, document_type, title, document_date, reference_number,
+            original_file_name, content_hash, file_size, expiry_date, created_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            document.staff_id,
+            document.document_type,
+            document.title,
+            document.document_date,
+            document.reference_number,
+            document.original_file_name,
+            document.content_hash,
+            document.file_size,
+            document.expiry_date,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+/// Deletes a document record, returning whether any other record still uses
+/// the same stored file
+pub fn delete_document_from_db(id: i32, content_hash: &str) -> Result<bool> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM staff_documents WHERE id = ?1", params![id])?;
+
+    let remaining: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM staff_documents WHERE content_hash = ?1",
+        params![content_hash],
+        |row| row.get(0),
+    )?;
+
+    Ok(remaining > 0)
+}
\ No newline at end of file
@@ -0,0 +1,287 @@
+use std::collections::{BTreeMap, BTreeSet};
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use chrono::{Local, Utc};
+use crate::database::models::{DuplicateStaffGroup, Staff, StaffMerge};
+use crate::database::operations::{
+    get_connection, get_all_staff_from_db, staff_from_row, add_column_if_missing, STAFF_COLUMNS
+};
+use crate::database::nic::enforce_unique_nic;
+use crate::database::reminders::{move_reminder_state, clear_document_reminder_state};
+use crate::database::salary::sync_current_salary;
+use crate::utils::nic_converter::convert_old_to_new_nic;
+
+pub fn create_duplicate_tables(conn: &Connection) -> Result<()> {
+    // Merged staff rows are deleted, so the audit keeps its own copy of them
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS staff_merges (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            kept_staff_id INTEGER NOT NULL,
+            merged_staff_id INTEGER NOT NULL,
+            merged_appointment_number TEXT NOT NULL,
+            merged_full_name TEXT NOT NULL,
+            merged_nic_number TEXT NOT NULL,
+            records_moved INTEGER NOT NULL,
+            records_discarded INTEGER NOT NULL,
+            merged_record TEXT NOT NULL,
+            merged_by TEXT,
+            merged_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Photo of the merged record, kept here when the kept record has its own
+    add_column_if_missing(conn, "staff_merges", "discarded_image_path", "TEXT")?;
+
+    Ok(())
+}
+
+/// Name with case, punctuation and spacing ignored, so "K. Sivakumar" and
+/// "k sivakumar" compare equal
+fn name_key(full_name: &str) -> String {
+    full_name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Staff records that look like the same person: those sharing a NIC number
+/// in either format, and those with the same name and date of birth
+pub fn find_duplicate_staff_from_db() -> Result<Vec<DuplicateStaffGroup>> {
+    let staff = get_all_staff_from_db()?;
+
+    let mut by_nic: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    let mut by_name: BTreeMap<(String, String), Vec<usize>> = BTreeMap::new();
+    for (index, member) in staff.iter().enumerate() {
+        let nic_number = convert_old_to_new_nic(member.nic_number.as_str())
+            .unwrap_or_else(|_| member.nic_number.as_str().trim().to_uppercase());
+        by_nic.entry(nic_number).or_default().push(index);
+        by_name.entry((name_key(&member.full_name), member.date_of_birth.clone())).or_default().push(index);
+    }
+
+    let candidates = by_nic.into_iter()
+        .map(|(nic_number, members)| (format!("Same NIC number {}", nic_number), members))
+        .chain(by_name.into_iter().map(|((_, date_of_birth), members)| {
+            (format!("Same name and date of birth {}", date_of_birth), members)
+        }));
+
+    // Records sharing both NIC and name are reported once, under the NIC
+    let mut seen: BTreeSet<Vec<usize>> = BTreeSet::new();
+    let mut groups = Vec::new();
+    for (reason, members) in candidates {
+        if members.len() < 2 || !seen.insert(members.clone()) {
+            continue;
+        }
+
+        groups.push(DuplicateStaffGroup {
+            reason,
+            staff: members.into_iter().map(|index| staff[index].clone()).collect(),
+        });
+    }
+
+    Ok(groups)
+}
+
+/// Every table with a foreign key to `staff`, with the referencing column.
+/// These rows belong to a staff record and move with it on a merge; read
+/// from the schema so tables added later are never left to the cascade.
+fn staff_record_tables(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare(
+        "SELECT m.name, f.\"from\" FROM sqlite_master m, pragma_foreign_key_list(m.name) f
+         WHERE m.type = 'table' AND f.\"table\" = 'staff'
+         ORDER BY m.name"
+    )?;
+    let tables = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    tables.collect()
+}
+
+/// Which rows of a staff record table move on a merge. Rows that do not
+/// match stay with the merged record and are discarded with it.
+fn moved_rows(table: &str) -> &'static str {
+    match table {
+        // The kept record's salary history stays the only one; taking the
+        // merged record's as well would let its entries become the salary
+        "salary_history" => "0",
+        // Pending promotions carry a salary entry that is not moved
+        "promotions" => "applied = 1",
+        // The same posting or leave recorded on both records is kept once.
+        // Other overlaps are refused before merging.
+        "postings" => "NOT EXISTS (SELECT 1 FROM postings kept
+            WHERE kept.staff_id = ?1 AND kept.station = postings.station
+            AND kept.from_date = postings.from_date AND kept.to_date IS postings.to_date)",
+        "leave_applications" => "NOT EXISTS (SELECT 1 FROM leave_applications kept
+            WHERE kept.staff_id = ?1 AND kept.leave_type_id = leave_applications.leave_type_id
+            AND kept.from_date = leave_applications.from_date AND kept.to_date = leave_applications.to_date)",
+        _ => "1",
+    }
+}
+
+fn staff_in(conn: &Connection, id: i32) -> Result<Option<Staff>> {
+    conn.query_row(
+        &format!("SELECT {} FROM staff WHERE id = ?1", STAFF_COLUMNS),
+        params![id],
+        staff_from_row,
+    ).optional()
+}
+
+/// Folds the `merge_id` staff record into `keep_id`. Its sub-records move
+/// across, except those the kept record already has (such as a leave balance
+/// for the same year), which are discarded, as is its salary history. Its
+/// photo and any optional details the kept record lacks are carried over.
+/// The merged record is then deleted and the merge written to the audit.
+/// Overlapping postings and leave should be checked by the caller first.
+pub fn merge_staff_in_db(keep_id: i32, merge_id: i32, merged_by: Option<&str>) -> Result<StaffMerge> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    if staff_in(&tx, keep_id)?.is_none() {
+        return Err(rusqlite::Error::QueryReturnedNoRows);
+    }
+    let merged = staff_in(&tx, merge_id)?.ok_or(rusqlite::Error::QueryReturnedNoRows)?;
+
+    let mut records_moved = 0;
+    let mut records_discarded = 0;
+    for (table, column) in staff_record_tables(&tx)? {
+        records_moved += tx.execute(
+            &format!(
+                "UPDATE OR IGNORE \"{0}\" SET \"{1}\" = ?1 WHERE \"{1}\" = ?2 AND {2}",
+                table, column, moved_rows(&table)
+            ),
+            params![keep_id, merge_id],
+        )? as i32;
+        records_discarded += tx.query_row(
+            &format!("SELECT COUNT(*) FROM \"{}\" WHERE \"{}\" = ?1", table, column),
+            params![merge_id],
+            |row| row.get::<_, i32>(0),
+        )?;
+    }
+
+    // Snoozed or dismissed reminders follow the record, except those for
+    // documents the kept record already had
+    move_reminder_state(&tx, merge_id, keep_id)?;
+    let mut stmt = tx.prepare("SELECT id FROM staff_documents WHERE staff_id = ?1")?;
+    let discarded_documents = stmt.query_map(params![merge_id], |row| row.get::<_, i32>(0))?
+        .collect::<Result<Vec<_>>>()?;
+    drop(stmt);
+    for document_id in discarded_documents {
+        clear_document_reminder_state(&tx, document_id)?;
+    }
+
+    // A photo that is not carried over is left on disk and noted in the audit
+    let kept_image_path: Option<String> = tx.query_row(
+        "SELECT image_path FROM staff WHERE id = ?1",
+        params![keep_id],
+        |row| row.get(0),
+    )?;
+    let discarded_image_path = merged.image_path.clone()
+        .filter(|path| kept_image_path.as_ref().is_some_and(|kept| kept != path));
+
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    tx.execute(
+        "UPDATE staff SET
+            image_path = COALESCE(image_path, ?1),
+            email = COALESCE(email, ?2),
+            address_line2 = COALESCE(address_line2, ?3),
+            address_line3 = COALESCE(address_line3, ?4),
+            probation_period_months = COALESCE(probation_period_months, ?5),
+            probation_end_date = COALESCE(probation_end_date, ?6),
+            confirmation_date = COALESCE(confirmation_date, ?7),
+            confirmation_reference = COALESCE(confirmation_reference, ?8),
+            updated_at = ?9
+         WHERE id = ?10",
+        params![
+            merged.image_path,
+            merged.email,
+            merged.address_line2,
+            merged.address_line3,
+            merged.probation_period_months,
+            merged.probation_end_date,
+            merged.confirmation_date,
+            merged.confirmation_reference,
+            now,
+            keep_id
+        ],
+    )?;
+
+    let merged_record = serde_json::to_string(&merged)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+    tx.execute(
+        "INSERT INTO staff_merges (
+            kept_staff_id, merged_staff_id, merged_appointment_number, merged_full_name,
+            merged_nic_number, records_moved, records_discarded, merged_record, merged_by, merged_at,
+            discarded_image_path
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            keep_id,
+            merge_id,
+            merged.appointment_number,
+            merged.full_name,
+            merged.nic_number.as_str(),
+            records_moved,
+            records_discarded,
+            merged_record,
+            merged_by,
+            now,
+            discarded_image_path
+        ],
+    )?;
+    let id = tx.last_insert_rowid() as i32;
+
+    tx.execute("DELETE FROM staff WHERE id = ?1", params![merge_id])?;
+    sync_current_salary(&tx, keep_id, Local::now().date_naive())?;
+    tx.commit()?;
+
+    // The merge may have removed the last NIC number held twice
+    enforce_unique_nic(&conn)?;
+
+    Ok(StaffMerge {
+        id: Some(id),
+        kept_staff_id: keep_id,
+        merged_staff_id: merge_id,
+        merged_appointment_number: merged.appointment_number,
+        merged_full_name: merged.full_name,
+        merged_nic_number: merged.nic_number.to_string(),
+        records_moved,
+        records_discarded,
+        merged_record,
+        merged_by: merged_by.map(str::to_string),
+        merged_at: Some(now),
+        discarded_image_path,
+    })
+}
+
+pub fn get_staff_merges_from_db() -> Result<Vec<StaffMerge>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, kept_staff_id, merged_staff_id, merged_appointment_number, merged_full_name,
+                merged_nic_number, records_moved, records_discarded, merged_record, merged_by, merged_at,
+                discarded_image_path
+         FROM staff_merges ORDER BY merged_at DESC, id DESC"
+    )?;
+
+    let merge_iter = stmt.query_map([], |row| {
+        Ok(StaffMerge {
+            id: Some(row.get(0)?),
+            kept_staff_id: row.get(1)?,
+            merged_staff_id: row.get(2)?,
+            merged_appointment_number: row.get(3)?,
+            merged_full_name: row.get(4)?,
+            merged_nic_number: row.get(5)?,
+            records_moved: row.get(6)?,
+            records_discarded: row.get(7)?,
+            merged_record: row.get(8)?,
+            merged_by: row.get(9)?,
+            merged_at: Some(row.get(10)?),
+            discarded_image_path: row.get(11)?,
+        })
+    })?;
+
+    let mut merges = Vec::new();
+    for merge in merge_iter {
+        merges.push(merge?);
+    }
+
+    Ok(merges)
+}
\ No newline at end of file
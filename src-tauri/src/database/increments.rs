@@ -0,0 +1,199 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{Datelike, Local, Utc};
+use crate::database::models::{IncrementDue, IncrementPreview, AppliedIncrement, SalaryHistoryEntry};
+use crate::database::operations::get_connection;
+use crate::database::salary::{insert_salary_history, sync_current_salary, current_salary_scale};
+use crate::utils::salary_scale::locate_on_scale;
+use crate::database::reports::retirement_date_of;
+use crate::utils::date_utils::{day_month_in_year, format_date};
+
+pub fn create_increment_tables(conn: &Connection) -> Result<()> {
+    // One row per increment applied; the UNIQUE constraint guarantees an
+    // increment can never be applied twice to the same person in a year
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS applied_increments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            increment_year INTEGER NOT NULL,
+            effective_date TEXT NOT NULL,
+            previous_salary REAL NOT NULL,
+            increment_amount REAL NOT NULL,
+            new_salary REAL NOT NULL,
+            applied_at TEXT NOT NULL,
+            UNIQUE(staff_id, increment_year)
+        )",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Staff whose increment falls due in the given month, excluding anyone with
+/// no increment amount or who retires before the increment date
+fn increments_due(conn: &Connection, year: i32, month: u32) -> Result<Vec<IncrementDue>> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.appointment_number, s.full_name, s.designation, s.increment_date,
+         s.basic_salary, s.increment_amount, s.date_of_retirement, s.date_of_birth,
+         a.id IS NOT NULL
+         FROM staff s
+         LEFT JOIN applied_increments a ON a.staff_id = s.id AND a.increment_year = ?1
+         ORDER BY s.full_name"
+    )?;
+
+    let rows = stmt.query_map(params![year], |row| {
+        Ok((
+            row.get::<_, String>(4)?,
+            row.get::<_, String>(7)?,
+            row.get::<_, String>(8)?,
+            IncrementDue {
+                staff_id: row.get(0)?,
+                appointment_number: row.get(1)?,
+                full_name: row.get(2)?,
+                designation: row.get(3)?,
+                effective_date: String::new(),
+                current_basic_salary: row.get(5)?,
+                increment_amount: row.get(6)?,
+                new_basic_salary: 0.0,
+                already_applied: row.get(9)?,
+            },
+        ))
+    })?;
+
+    let mut due = Vec::new();
+    for row in rows {
+        let (increment_date, date_of_retirement, date_of_birth, mut entry) = row?;
+
+        let effective = match day_month_in_year(&increment_date, year) {
+            Some(date) if date.month() == month => date,
+            _ => continue,
+        };
+
+        if entry.increment_amount <= 0.0 {
+            continue;
+        }
+
+        if let Some(retirement) = retirement_date_of(&date_of_retirement, &date_of_birth) {
+            if retirement < effective {
+                continue;
+            }
+        }
+
+        entry.effective_date = format_date(effective);
+        entry.new_basic_salary = entry.current_basic_salary + entry.increment_amount;
+        due.push(entry);
+    }
+
+    Ok(due)
+}
+
+pub fn get_increments_due_from_db(year: i32, month: u32) -> Result<Vec<IncrementDue>> {
+    let conn = get_connection()?;
+    increments_due(&conn, year, month)
+}
+
+pub fn preview_increments_from_db(year: i32, month: u32) -> Result<IncrementPreview> {
+    let conn = get_connection()?;
+    let pending: Vec<IncrementDue> = increments_due(&conn, year, month)?
+        .into_iter()
+        .filter(|entry| !entry.already_applied)
+        .collect();
+
+    Ok(IncrementPreview {
+        year,
+        month,
+        total_increase: pending.iter().map(|entry| entry.increment_amount).sum(),
+        pending,
+    })
+}
+
+/// Applies pending increments for the month in a single transaction. When
+/// `staff_ids` is given only those staff members are incremented. The new
+/// salary is recorded in the history and reaches the staff record on the
+/// increment date.
+pub fn apply_increments_in_db(year: i32, month: u32, staff_ids: Option<&[i32]>) -> Result<Vec<IncrementDue>> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let pending: Vec<IncrementDue> = increments_due(&tx, year, month)?
+        .into_iter()
+        .filter(|entry| !entry.already_applied)
+        .filter(|entry| staff_ids.is_none_or(|ids| ids.contains(&entry.staff_id)))
+        .collect();
+
+    for entry in &pending {
+        tx.execute(
+            "INSERT INTO applied_increments (
+                staff_id, increment_year, effective_date, previous_salary,
+                increment_amount, new_salary, applied_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                entry.staff_id,
+                year,
+                entry.effective_date,
+                entry.current_basic_salary,
+                entry.increment_amount,
+                entry.new_basic_salary,
+                now
+            ],
+        )?;
+
+        let salary_code: String = tx.query_row(
+            "SELECT salary_code FROM staff WHERE id = ?1",
+            params![entry.staff_id],
+            |row| row.get(0),
+        )?;
+
+        // Move on to the increment for the next step when the scale is known
+        let next_increment = current_salary_scale(&tx, &salary_code)?
+            .and_then(|scale| locate_on_scale(scale.initial_salary, &scale.segment_tuples(), entry.new_basic_salary))
+            .map_or(entry.increment_amount, |position| position.increment_amount);
+
+        insert_salary_history(&tx, &SalaryHistoryEntry {
+            id: None,
+            staff_id: entry.staff_id,
+            effective_date: entry.effective_date.clone(),
+            salary_code,
+            basic_salary: entry.new_basic_salary,
+            increment_amount: next_increment,
+            reason: "increment".to_string(),
+            reference_number: None,
+            remarks: None,
+            created_at: None,
+            updated_at: None,
+        })?;
+        sync_current_salary(&tx, entry.staff_id, Local::now().date_naive())?;
+    }
+
+    tx.commit()?;
+    Ok(pending)
+}
+
+pub fn get_applied_increments_from_db(staff_id: i32) -> Result<Vec<AppliedIncrement>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, increment_year, effective_date, previous_salary,
+         increment_amount, new_salary, applied_at
+         FROM applied_increments WHERE staff_id = ?1 ORDER BY increment_year"
+    )?;
+
+    let increment_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(AppliedIncrement {
+            id: row.get(0)?,
+            staff_id: row.get(1)?,
+            increment_year: row.get(2)?,
+            effective_date: row.get(3)?,
+            previous_salary: row.get(4)?,
+            increment_amount: row.get(5)?,
+            new_salary: row.get(6)?,
+            applied_at: row.get(7)?,
+        })
+    })?;
+
+    let mut increments = Vec::new();
+    for increment in increment_iter {
+        increments.push(increment?);
+    }
+
+    Ok(increments)
+}
\ No newline at end of file
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use chrono::{Datelike, Utc};
+use crate::database::models::{LeaveType, LeaveApplication, LeaveBalance, LeaveRegisterEntry};
+use crate::database::operations::get_connection;
+use crate::utils::date_utils::parse_date;
+
+// Starting entitlements and carry-forward limits; adjust through
+// `update_leave_type` when the Establishments Code rules change. Casual leave
+// lapses at the end of the year; unused vacation leave may be taken the
+// following year, up to one year's entitlement.
+const DEFAULT_LEAVE_TYPES: [(&str, &str, Option<f64>, f64, bool); 5] = [
+    ("casual", "Casual Leave", Some(7.0), 0.0, true),
+    ("vacation", "Vacation Leave", Some(24.0), 24.0, true),
+    ("sick", "Sick Leave", Some(21.0), 0.0, true),
+    ("maternity", "Maternity Leave", Some(84.0), 0.0, true),
+    ("no_pay", "No-Pay Leave", None, 0.0, false),
+];
+
+pub fn create_leave_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS leave_types (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            code TEXT NOT NULL UNIQUE,
+            name TEXT NOT NULL,
+            annual_entitlement REAL,
+            max_carry_forward REAL NOT NULL DEFAULT 0,
+            is_paid INTEGER NOT NULL DEFAULT 1
+        )",
+        [],
+    )?;
+
+    // Per-year overrides of the default entitlement, and days carried forward
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS leave_balances (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            leave_type_id INTEGER NOT NULL REFERENCES leave_types(id),
+            year INTEGER NOT NULL,
+            entitlement REAL,
+            carried_forward REAL NOT NULL DEFAULT 0,
+            UNIQUE(staff_id, leave_type_id, year)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS leave_applications (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            leave_type_id INTEGER NOT NULL REFERENCES leave_types(id),
+            from_date TEXT NOT NULL,
+            to_date TEXT NOT NULL,
+            days REAL NOT NULL,
+            reason TEXT,
+            status TEXT NOT NULL DEFAULT 'pending',
+            decided_by TEXT,
+            decision_remarks TEXT,
+            decided_at TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_leave_applications_staff ON leave_applications(staff_id)",
+        [],
+    )?;
+
+    for (code, name, entitlement, carry_forward, is_paid) in DEFAULT_LEAVE_TYPES {
+        conn.execute(
+            "INSERT OR IGNORE INTO leave_types (code, name, annual_entitlement, max_carry_forward, is_paid)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![code, name, entitlement, carry_forward, is_paid],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn leave_types(conn: &Connection) -> Result<Vec<LeaveType>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, code, name, annual_entitlement, max_carry_forward, is_paid
+         FROM leave_types ORDER BY id"
+    )?;
+
+    let type_iter = stmt.query_map([], |row| {
+        Ok(LeaveType {
+            id: Some(row.get(0)?),
+            code: row.get(1)?,
+            name: row.get(2)?,
+            annual_entitlement: row.get(3)?,
+            max_carry_forward: row.get(4)?,
+            is_paid: row.get(5)?,
+        })
+    })?;
+
+    let mut types = Vec::new();
+    for leave_type in type_iter {
+        types.push(leave_type?);
+    }
+
+    Ok(types)
+}
+
+pub fn get_leave_types_from_db() -> Result<Vec<LeaveType>> {
+    let conn = get_connection()?;
+    leave_types(&conn)
+}
+
+pub fn update_leave_type_in_db(leave_type: &LeaveType) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "UPDATE leave_types SET name = ?1, annual_entitlement = ?2, max_carry_forward = ?3, is_paid = ?4
+         WHERE id = ?5",
+        params![
+            leave_type.name,
+            leave_type.annual_entitlement,
+            leave_type.max_carry_forward,
+            leave_type.is_paid,
+            leave_type.id
+        ],
+    )?;
+    Ok(())
+}
+
+fn application_from_row(row: &rusqlite::Row) -> Result<LeaveApplication> {
+    Ok(LeaveApplication {
+        id: Some(row.get(0)?),
+        staff_id: row.get(1)?,
+        leave_type_id: row.get(2)?,
+        from_date: row.get(3)?,
+        to_date: row.get(4)?,
+        days: row.get(5)?,
+        reason: row.get(6)?,
+        status: row.get(7)?,
+        decided_by: row.get(8)?,
+        decision_remarks: row.get(9)?,
+        decided_at: row.get(10)?,
+        created_at: Some(row.get(11)?),
+        updated_at: Some(row.get(12)?),
+    })
+}
+
+const APPLICATION_COLUMNS: &str = "id, staff_id, leave_type_id, from_date, to_date, days, reason, status,
+    decided_by, decision_remarks, decided_at, created_at, updated_at";
+
+fn leave_applications(conn: &Connection, staff_id: i32) -> Result<Vec<LeaveApplication>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM leave_applications WHERE staff_id = ?1 ORDER BY id",
+        APPLICATION_COLUMNS
+    ))?;
+
+    let application_iter = stmt.query_map(params![staff_id], application_from_row)?;
+
+    let mut applications = Vec::new();
+    for application in application_iter {
+        applications.push(application?);
+    }
+
+    Ok(applications)
+}
+
+pub fn get_leave_applications_from_db(staff_id: i32) -> Result<Vec<LeaveApplication>> {
+    let conn = get_connection()?;
+    leave_applications(&conn, staff_id)
+}
+
+pub fn get_leave_application_from_db(id: i32) -> Result<Option<LeaveApplication>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        &format!("SELECT {} FROM leave_applications WHERE id = ?1", APPLICATION_COLUMNS),
+        params![id],
+        application_from_row,
+    ).optional()
+}
+
+pub fn insert_leave_application_in_db(application: &LeaveApplication) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO leave_applications (
+            staff_id, leave_type_id, from_date, to_date, days, reason, status, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'pending', ?7, ?8)",
+        params![
+            application.staff_id,
+            application.leave_type_id,
+            application.from_date,
+            application.to_date,
+            application.days,
+            application.reason,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn set_leave_status_in_db(id: i32, status: &str, decided_by: Option<&str>, remarks: Option<&str>) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE leave_applications SET
+            status = ?1, decided_by = ?2, decision_remarks = ?3, decided_at = ?4, updated_at = ?5
+         WHERE id = ?6",
+        params![status, decided_by, remarks, now, now, id],
+    )?;
+
+    Ok(())
+}
+
+/// Leave year of an application: the year in which it starts
+fn leave_year(application: &LeaveApplication) -> Option<i32> {
+    parse_date(&application.from_date).map(|date| date.year())
+}
+
+fn leave_balances(conn: &Connection, staff_id: i32, year: i32) -> Result<Vec<LeaveBalance>> {
+    let mut stmt = conn.prepare(
+        "SELECT leave_type_id, entitlement, carried_forward FROM leave_balances
+         WHERE staff_id = ?1 AND year = ?2"
+    )?;
+    let overrides: HashMap<i32, (Option<f64>, f64)> = stmt
+        .query_map(params![staff_id, year], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    let applications: Vec<LeaveApplication> = leave_applications(conn, staff_id)?
+        .into_iter()
+        .filter(|application| leave_year(application) == Some(year))
+        .collect();
+
+    let mut balances = Vec::new();
+    for leave_type in leave_types(conn)? {
+        let leave_type_id = leave_type.id.unwrap_or_default();
+        let (entitlement, carried_forward) = match overrides.get(&leave_type_id) {
+            Some((entitlement, carried)) => (entitlement.or(leave_type.annual_entitlement), *carried),
+            None => (leave_type.annual_entitlement, 0.0),
+        };
+
+        let days_with_status = |status: &str| -> f64 {
+            applications.iter()
+                .filter(|a| a.leave_type_id == leave_type_id && a.status == status)
+                .fold(0.0, |total, a| total + a.days)
+        };
+        let taken = days_with_status("approved");
+
+        balances.push(LeaveBalance {
+            leave_type_id,
+            leave_type: leave_type.name,
+            year,
+            entitlement,
+            carried_forward,
+            taken,
+            pending: days_with_status("pending"),
+            available: entitlement.map(|entitlement| entitlement + carried_forward - taken),
+        });
+    }
+
+    Ok(balances)
+}
+
+pub fn get_leave_balances_from_db(staff_id: i32, year: i32) -> Result<Vec<LeaveBalance>> {
+    let conn = get_connection()?;
+    leave_balances(&conn, staff_id, year)
+}
+
+/// Sets a staff member's entitlement to a leave type for one year, or goes
+/// back to the leave type's annual entitlement when `entitlement` is None.
+/// Days already carried forward into the year are kept.
+pub fn set_leave_entitlement_in_db(staff_id: i32, leave_type_id: i32, year: i32, entitlement: Option<f64>) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "INSERT INTO leave_balances (staff_id, leave_type_id, year, entitlement)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(staff_id, leave_type_id, year) DO UPDATE SET entitlement = excluded.entitlement",
+        params![staff_id, leave_type_id, year, entitlement],
+    )?;
+    Ok(())
+}
+
+/// Carries unused leave from `year` into the following year, up to each leave
+/// type's limit. Running it again for the same year recalculates the figures.
+pub fn carry_forward_leave_in_db(year: i32) -> Result<usize> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    let carry_limits: HashMap<i32, f64> = leave_types(&tx)?
+        .into_iter()
+        .filter(|leave_type| leave_type.max_carry_forward > 0.0)
+        .map(|leave_type| (leave_type.id.unwrap_or_default(), leave_type.max_carry_forward))
+        .collect();
+
+    let staff_ids = {
+        let mut stmt = tx.prepare("SELECT id FROM staff")?;
+        let ids = stmt.query_map([], |row| row.get::<_, i32>(0))?.collect::<Result<Vec<i32>>>()?;
+        ids
+    };
+
+    let mut carried = 0;
+    for staff_id in staff_ids {
+        for balance in leave_balances(&tx, staff_id, year)? {
+            let limit = match carry_limits.get(&balance.leave_type_id) {
+                Some(limit) => *limit,
+                None => continue,
+            };
+            let days = balance.available.unwrap_or(0.0).clamp(0.0, limit);
+
+            tx.execute(
+                "INSERT INTO leave_balances (staff_id, leave_type_id, year, carried_forward)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(staff_id, leave_type_id, year) DO UPDATE SET carried_forward = excluded.carried_forward",
+                params![staff_id, balance.leave_type_id, year + 1, days],
+            )?;
+            carried += 1;
+        }
+    }
+
+    tx.commit()?;
+    Ok(carried)
+}
+
+/// Approved applications starting in the given month, or running into it
+pub fn get_leave_register_from_db(year: i32, month: u32) -> Result<Vec<LeaveRegisterEntry>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT a.id, s.id, s.appointment_number, s.full_name, s.designation, t.name,
+         a.from_date, a.to_date, a.days
+         FROM leave_applications a
+         JOIN staff s ON s.id = a.staff_id
+         JOIN leave_types t ON t.id = a.leave_type_id
+         WHERE a.status = 'approved'
+         ORDER BY s.full_name, a.id"
+    )?;
+
+    let entry_iter = stmt.query_map([], |row| {
+        Ok(LeaveRegisterEntry {
+            application_id: row.get(0)?,
+            staff_id: row.get(1)?,
+            appointment_number: row.get(2)?,
+            full_name: row.get(3)?,
+            designation: row.get(4)?,
+            leave_type: row.get(5)?,
+            from_date: row.get(6)?,
+            to_date: row.get(7)?,
+            days: row.get(8)?,
+        })
+    })?;
+
+    let mut register = Vec::new();
+    for entry in entry_iter {
+        let entry = entry?;
+        let (from, to) = match (parse_date(&entry.from_date), parse_date(&entry.to_date)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => continue,
+        };
+        let starts_by_month_end = (from.year(), from.month()) <= (year, month);
+        let ends_after_month_start = (to.year(), to.month()) >= (year, month);
+        if starts_by_month_end && ends_after_month_start {
+            register.push(entry);
+        }
+    }
+
+    register.sort_by_key(|entry| parse_date(&entry.from_date));
+    Ok(register)
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::utils::nic_converter::Nic;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Staff {
@@ -10,7 +11,7 @@ pub struct Staff {
     pub gender: String,
     pub date_of_birth: String, // Format: dd-MM-yyyy
     pub age: i32,
-    pub nic_number: String, // Will be stored as new format
+    pub nic_number: Nic, // Validated and stored in the new 12-digit format
     pub marital_status: String,
     pub address_line1: String,
     pub address_line2: Option<String>,
@@ -19,10 +20,16 @@ pub struct Staff {
     pub email: Option<String>,
 
     // Employment Details
+    pub office_id: Option<i32>, // References offices(id); the default office when not given
     pub designation: String,
+    pub designation_id: Option<i32>, // References designations(id)
     pub date_of_first_appointment: String, // Format: dd-MM-yyyy
     pub date_of_retirement: String, // Auto-calculated
     pub increment_date: String, // Format: dd-MM
+    pub probation_period_months: Option<i32>,
+    pub probation_end_date: Option<String>, // Format: dd-MM-yyyy
+    pub confirmation_date: Option<String>, // Format: dd-MM-yyyy, set by confirm_probation
+    pub confirmation_reference: Option<String>,
 
     // Salary Information
     pub salary_code: String,
@@ -47,6 +54,9 @@ pub struct StaffSearch {
     pub age_min: Option<i32>,
     pub age_max: Option<i32>,
     pub nic_number: Option<String>,
+    pub qualification: Option<String>, // Has a qualification with this title
+    pub training: Option<String>, // Has completed a course with this name
+    pub office_id: Option<i32>, // All offices when not given
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -68,32 +78,607 @@ pub struct GenderCount {
     pub count: i32,
 }
 
-impl Default for Staff {
-    fn default() -> Self {
-        Staff {
-            id: None,
-            appointment_number: String::new(),
-            full_name: String::new(),
-            gender: String::from("Male"),
-            date_of_birth: String::new(),
-            age: 0,
-            nic_number: String::new(),
-            marital_status: String::from("Single"),
-            address_line1: String::new(),
-            address_line2: None,
-            address_line3: None,
-            contact_number: String::new(),
-            email: None,
-            designation: String::new(),
-            date_of_first_appointment: String::new(),
-            date_of_retirement: String::new(),
-            increment_date: String::new(),
-            salary_code: String::new(),
-            basic_salary: 0.0,
-            increment_amount: 0.0,
-            image_path: None,
-            created_at: None,
-            updated_at: None,
-        }
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkforceStatistics {
+    pub total: i32,
+    pub by_salary_code: Vec<CategoryCount>,
+    pub by_marital_status: Vec<CategoryCount>,
+    pub by_age_band: Vec<CategoryCount>,
+    pub by_service_band: Vec<CategoryCount>,
+    pub designation_by_gender: Vec<DesignationGenderCount>,
+    pub salary_by_designation: Vec<DesignationSalary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CategoryCount {
+    pub label: String,
+    pub count: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DesignationGenderCount {
+    pub designation: String,
+    pub male: i32,
+    pub female: i32,
+    pub total: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DesignationSalary {
+    pub designation: String,
+    pub count: i32,
+    pub average_basic_salary: f64,
+    pub min_basic_salary: f64,
+    pub max_basic_salary: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetirementForecast {
+    pub from_date: String, // Format: dd-MM-yyyy
+    pub to_date: String, // Format: dd-MM-yyyy
+    pub total_retiring: i32,
+    pub total_salary_bill: f64,
+    pub groups: Vec<RetirementGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetirementGroup {
+    pub year: i32,
+    pub designation: String,
+    pub count: i32,
+    pub salary_bill: f64, // Monthly basic salary leaving with this group
+    pub staff: Vec<RetiringStaff>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RetiringStaff {
+    pub id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub salary_code: String,
+    pub date_of_retirement: String, // Stored or derived from date of birth
+    pub basic_salary: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IncrementDue {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub current_basic_salary: f64,
+    pub increment_amount: f64,
+    pub new_basic_salary: f64,
+    pub already_applied: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncrementPreview {
+    pub year: i32,
+    pub month: u32,
+    pub pending: Vec<IncrementDue>,
+    pub total_increase: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppliedIncrement {
+    pub id: i32,
+    pub staff_id: i32,
+    pub increment_year: i32,
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub previous_salary: f64,
+    pub increment_amount: f64,
+    pub new_salary: f64,
+    pub applied_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SalaryHistoryEntry {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub salary_code: String,
+    pub basic_salary: f64,
+    pub increment_amount: f64,
+    pub reason: String, // appointment, increment, promotion, revision or correction
+    pub reference_number: Option<String>,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SalaryScale {
+    pub id: Option<i32>,
+    pub salary_code: String,
+    pub initial_salary: f64,
+    pub maximum_salary: f64,
+    pub segments: Vec<SalaryScaleSegment>,
+    pub circular_reference: Option<String>,
+    pub effective_from: String, // Format: dd-MM-yyyy
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SalaryScaleSegment {
+    pub step_count: i32,
+    pub increment_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SalaryScaleNotation {
+    pub salary_code: String,
+    pub notation: String, // e.g. "27,910 - 10x300 - 11x350 - 46,960"
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SalaryStep {
+    pub salary_code: String,
+    pub step: i32,
+    pub basic_salary: f64,
+    pub increment_amount: f64,
+    pub next_salary: Option<f64>,
+    pub maximum_salary: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Designation {
+    pub id: Option<i32>,
+    pub name: String,
+    pub service_grade: Option<String>,
+    pub rank_order: i32,
+    pub approved_cadre: i32,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DesignationVacancy {
+    pub designation_id: i32,
+    pub name: String,
+    pub service_grade: Option<String>,
+    pub approved_cadre: i32,
+    pub filled: i32,
+    pub vacant: i32, // Negative when the designation is over strength
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VacancyReport {
+    pub designations: Vec<DesignationVacancy>,
+    pub total_approved: i32,
+    pub total_filled: i32,
+    pub total_vacant: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Posting {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub station: String, // Range, beat or office the officer is posted to
+    pub station_type: Option<String>,
+    pub from_date: String, // Format: dd-MM-yyyy
+    pub to_date: Option<String>, // None while the posting is current
+    pub order_reference: Option<String>,
+    pub transfer_type: String,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferOrder {
+    pub staff_id: i32,
+    pub station: String,
+    pub station_type: Option<String>,
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub order_reference: Option<String>,
+    pub transfer_type: String,
+    pub remarks: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StationStaff {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub posting_id: i32,
+    pub station: String,
+    pub from_date: String,
+    pub to_date: Option<String>,
+    pub order_reference: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Promotion {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub from_designation: String,
+    pub from_designation_id: Option<i32>,
+    pub to_designation: String,
+    pub to_designation_id: Option<i32>,
+    pub from_salary_code: String,
+    pub to_salary_code: String,
+    pub basic_salary: f64, // Basic salary fixed on promotion
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub approval_reference: String,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PromotionRequest {
+    pub staff_id: i32,
+    pub to_designation: String,
+    pub to_salary_code: String,
+    pub effective_date: String, // Format: dd-MM-yyyy
+    pub approval_reference: String,
+    pub basic_salary: Option<f64>, // Keeps the current basic salary when not given
+    pub increment_amount: Option<f64>, // Taken from the salary scale when not given
+    pub remarks: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SeniorityEntry {
+    pub rank: i32,
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub in_designation_since: String, // Date of promotion to, or appointment in, the designation
+    pub date_of_first_appointment: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeaveType {
+    pub id: Option<i32>,
+    pub code: String,
+    pub name: String,
+    pub annual_entitlement: Option<f64>, // Days per year, None for no limit
+    pub max_carry_forward: f64, // Days that may be carried into the next year
+    pub is_paid: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LeaveApplication {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub leave_type_id: i32,
+    pub from_date: String, // Format: dd-MM-yyyy
+    pub to_date: String, // Format: dd-MM-yyyy
+    pub days: f64, // Working days, or a half day
+    pub reason: Option<String>,
+    pub status: String, // pending, approved or rejected
+    pub decided_by: Option<String>,
+    pub decision_remarks: Option<String>,
+    pub decided_at: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LeaveBalance {
+    pub leave_type_id: i32,
+    pub leave_type: String,
+    pub year: i32,
+    pub entitlement: Option<f64>,
+    pub carried_forward: f64,
+    pub taken: f64,
+    pub pending: f64,
+    pub available: Option<f64>, // None for leave types without a limit
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LeaveRegisterEntry {
+    pub application_id: i32,
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub leave_type: String,
+    pub from_date: String,
+    pub to_date: String,
+    pub days: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttendanceMapping {
+    pub device_user_id: String,
+    pub staff_id: i32,
+    pub appointment_number: Option<String>,
+    pub full_name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OfficeHours {
+    pub start_time: String, // Format: HH:MM
+    pub end_time: String, // Format: HH:MM
+    pub grace_minutes: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Holiday {
+    pub id: Option<i32>,
+    pub holiday_date: String, // Format: dd-MM-yyyy
+    pub name: String, // e.g. "Vesak Full Moon Poya Day"
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttendanceImportResult {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub invalid_lines: Vec<usize>,
+    pub unmapped_user_ids: Vec<String>, // Stored, but not counted until mapped to staff
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DailyAttendance {
+    pub date: String, // Format: dd-MM-yyyy
+    pub check_in: Option<String>, // Format: HH:MM
+    pub check_out: Option<String>, // None when there was a single punch
+    pub late_minutes: i64,
+    pub left_early: bool,
+    pub status: String, // present, late, leave, absent, or pending for today before its punches are imported
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AttendanceSummary {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub working_days: i32,
+    pub present_days: i32,
+    pub late_days: i32,
+    pub leave_days: i32,
+    pub absent_days: i32,
+    pub absent_dates: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dependent {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub relationship: String, // spouse, son, daughter, father, mother or other
+    pub full_name: String,
+    pub gender: Option<String>,
+    pub date_of_birth: Option<String>, // Format: dd-MM-yyyy
+    pub nic_number: Option<String>, // Stored in new format
+    pub wop_nominee: bool, // Widows' and orphans' pension beneficiary
+    pub gratuity_nominee: bool,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Qualification {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub category: String, // academic or professional
+    pub title: String,
+    pub institution: String,
+    pub awarded_date: Option<String>, // Format: dd-MM-yyyy
+    pub result: Option<String>, // Class, grade or pass
+    pub certificate_path: Option<String>,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrainingCourse {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub course_name: String,
+    pub institution: String,
+    pub start_date: String, // Format: dd-MM-yyyy
+    pub end_date: Option<String>, // Format: dd-MM-yyyy
+    pub completed: bool,
+    pub result: Option<String>,
+    pub certificate_path: Option<String>,
+    pub remarks: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StaffDocument {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub document_type: String,
+    pub title: String,
+    pub document_date: Option<String>, // Format: dd-MM-yyyy
+    pub reference_number: Option<String>,
+    pub original_file_name: Option<String>, // Set when the file is attached
+    pub content_hash: Option<String>, // SHA-256 of the stored file
+    pub file_size: Option<i64>,
+    pub expiry_date: Option<String>, // Format: dd-MM-yyyy, for permits and licences
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StaffNote {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub note_date: String, // Format: dd-MM-yyyy
+    pub category: String, // warning, inquiry, commendation, disciplinary_action or note
+    pub reference_number: Option<String>,
+    pub description: String,
+    pub outcome: Option<String>,
+    pub recorded_by: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PensionRules {
+    pub minimum_service_years: i32,
+    pub base_percentage: f64, // Pension as % of last salary at minimum service
+    pub percentage_per_year: f64, // Added for each year beyond the minimum
+    pub maximum_percentage: f64,
+    pub gratuity_months: f64, // Commuted gratuity, in months of full pension
+    pub commutation_reduction: f64, // % by which the pension is reduced while the gratuity is recovered
+    pub commutation_years: i32, // Years over which the gratuity is recovered
+    pub short_service_gratuity_months: f64, // Months of salary per year of service when below the minimum
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PensionEstimate {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub date_of_first_appointment: String,
+    pub retirement_date: String,
+    pub service_years: i32,
+    pub service_months: i32, // Months beyond the completed years
+    pub last_basic_salary: f64,
+    pub pensionable: bool,
+    pub pension_percentage: f64,
+    pub monthly_pension: f64,
+    pub gratuity: f64,
+    pub reduced_monthly_pension: f64, // Paid until the gratuity is recovered
+    pub full_pension_from: Option<String>, // Format: dd-MM-yyyy
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServiceBreak {
+    pub id: Option<i32>,
+    pub staff_id: i32,
+    pub from_date: String, // Format: dd-MM-yyyy
+    pub to_date: String, // Format: dd-MM-yyyy
+    pub reason: String,
+    pub reference_number: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceLength {
+    pub staff_id: i32,
+    pub date_of_first_appointment: String,
+    pub as_of: String, // Format: dd-MM-yyyy
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+    pub gross_days: i64,
+    pub break_days: i64,
+    pub no_pay_days: i64,
+    pub deducted_days: i64, // Breaks and no-pay leave, overlapping days counted once
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceMilestone {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub years: i32,
+    pub milestone: String,
+    pub milestone_date: String, // Format: dd-MM-yyyy
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProbationDue {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub designation: String,
+    pub date_of_first_appointment: String,
+    pub probation_end_date: String,
+    pub days_remaining: i64, // Negative once the probation has lapsed
+    pub lapsed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Reminder {
+    pub key: String, // Identifies this occurrence, e.g. birthday:12:10-07-2027
+    pub category: String, // retirement, increment, probation, birthday or document
+    pub staff_id: i32,
+    pub full_name: String,
+    pub title: String,
+    pub due_date: String, // Format: dd-MM-yyyy
+    pub days_remaining: i64, // Negative once overdue
+    pub status: String, // active, snoozed or dismissed
+    pub snoozed_until: Option<String>, // Format: dd-MM-yyyy
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Office {
+    pub id: Option<i32>,
+    pub code: String, // Short code, e.g. VAV
+    pub name: String,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub letterhead_title: String, // First line of the PDF letterhead
+    pub letterhead_subtitle: String,
+    pub is_default: bool, // New staff and combined reports use this office
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NicDetails {
+    pub nic_number: String, // New 12-digit format
+    pub date_of_birth: String, // Format: dd-MM-yyyy
+    pub gender: String,
+    pub age: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NicMismatch {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub nic_number: String,
+    pub date_of_birth: String,
+    pub gender: String,
+    pub mismatches: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NicNormalizationFailure {
+    pub staff_id: i32,
+    pub appointment_number: String,
+    pub full_name: String,
+    pub nic_number: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NicNormalization {
+    pub converted: i32, // Rewritten from the old format
+    pub unchanged: i32, // Already in the new format
+    pub failed: Vec<NicNormalizationFailure>, // Left as stored
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DuplicateStaffGroup {
+    pub reason: String, // Why the records look like the same person
+    pub staff: Vec<Staff>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StaffMerge {
+    pub id: Option<i32>,
+    pub kept_staff_id: i32,
+    pub merged_staff_id: i32, // No longer exists once merged
+    pub merged_appointment_number: String,
+    pub merged_full_name: String,
+    pub merged_nic_number: String,
+    pub records_moved: i32, // Sub-records moved to the kept record
+    pub records_discarded: i32, // Sub-records not carried over, such as ones the kept record already had
+    pub merged_record: String, // JSON copy of the merged staff record
+    pub merged_by: Option<String>,
+    pub merged_at: Option<String>,
+    pub discarded_image_path: Option<String>, // Merged record's photo, when the kept record had its own
+}
+
+impl SalaryScale {
+    pub fn segment_tuples(&self) -> Vec<(i32, f64)> {
+        self.segments.iter().map(|s| (s.step_count, s.increment_amount)).collect()
     }
 }
\ No newline at end of file
@@ -0,0 +1,148 @@
+use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Result};
+use chrono::Utc;
+use crate::database::models::{NicMismatch, NicNormalization, NicNormalizationFailure};
+use crate::database::operations::{get_connection, get_all_staff_from_db};
+use crate::utils::nic_converter::{nic_mismatches, convert_old_to_new_nic};
+
+/// Converts old-format NIC numbers on existing staff rows to the new format.
+/// Runs on every start so records restored or imported in the old format are
+/// converted too; once all are converted it only reads the table. Numbers
+/// that cannot be converted are left for correction; `normalize_nic_numbers`
+/// lists them.
+pub fn migrate_nic_numbers(conn: &Connection) -> Result<()> {
+    let normalization = normalize_nic_numbers(conn)?;
+
+    if !normalization.failed.is_empty() {
+        eprintln!(
+            "{} NIC numbers could not be converted to the new format; normalize NIC numbers to list them",
+            normalization.failed.len()
+        );
+    }
+
+    enforce_unique_nic(conn)
+}
+
+/// Makes NIC numbers unique once no two staff records share one. Until the
+/// duplicates are merged a plain index is kept and a warning is logged.
+pub fn enforce_unique_nic(conn: &Connection) -> Result<()> {
+    let shared: i32 = conn.query_row(
+        "SELECT COUNT(*) FROM (SELECT nic_number FROM staff GROUP BY nic_number HAVING COUNT(*) > 1)",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if shared > 0 {
+        eprintln!(
+            "{} NIC numbers are shared by more than one staff record; merge the duplicates to make NIC numbers unique",
+            shared
+        );
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_staff_nic ON staff(nic_number)", [])?;
+        return Ok(());
+    }
+
+    conn.execute("DROP INDEX IF EXISTS idx_staff_nic", [])?;
+    conn.execute("CREATE UNIQUE INDEX IF NOT EXISTS idx_staff_nic_unique ON staff(nic_number)", [])?;
+    Ok(())
+}
+
+/// Another staff record holding the same NIC number, as (id, appointment
+/// number, full name)
+pub fn find_other_staff_with_nic_from_db(nic_number: &str, staff_id: Option<i32>) -> Result<Option<(i32, String, String)>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        "SELECT id, appointment_number, full_name FROM staff
+         WHERE nic_number = ?1 AND (?2 IS NULL OR id != ?2) LIMIT 1",
+        params![nic_number, staff_id],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ).optional()
+}
+
+fn normalize_nic_numbers(conn: &Connection) -> Result<NicNormalization> {
+    let mut stmt = conn.prepare("SELECT id, appointment_number, full_name, nic_number FROM staff ORDER BY id")?;
+    let staff_iter = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+
+    let mut staff = Vec::new();
+    for s in staff_iter {
+        staff.push(s?);
+    }
+
+    // A failed update only undoes its own statement, so one transaction
+    // still covers every conversion that succeeds
+    let tx = conn.unchecked_transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let mut normalization = NicNormalization { converted: 0, unchanged: 0, failed: Vec::new() };
+
+    for (staff_id, appointment_number, full_name, nic_number) in staff {
+        match convert_old_to_new_nic(&nic_number) {
+            Ok(new_nic) if new_nic == nic_number => normalization.unchanged += 1,
+            Ok(new_nic) => {
+                let updated = tx.execute(
+                    "UPDATE staff SET nic_number = ?1, updated_at = ?2 WHERE id = ?3",
+                    params![new_nic, now, staff_id],
+                );
+
+                match updated {
+                    Ok(_) => normalization.converted += 1,
+                    // Once NIC numbers are unique the new form may already be held by a duplicate
+                    Err(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::ConstraintViolation => {
+                        normalization.failed.push(NicNormalizationFailure {
+                            staff_id,
+                            appointment_number,
+                            full_name,
+                            nic_number,
+                            error: format!("{} already belongs to another staff record", new_nic),
+                        });
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(e) => normalization.failed.push(NicNormalizationFailure {
+                staff_id,
+                appointment_number,
+                full_name,
+                nic_number,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    tx.commit()?;
+    Ok(normalization)
+}
+
+pub fn normalize_nic_numbers_in_db() -> Result<NicNormalization> {
+    let conn = get_connection()?;
+    normalize_nic_numbers(&conn)
+}
+
+/// Every staff record whose date of birth or gender disagrees with its NIC
+/// number, or whose NIC number cannot be read
+pub fn get_nic_mismatches_from_db() -> Result<Vec<NicMismatch>> {
+    let mut report = Vec::new();
+
+    for staff in get_all_staff_from_db()? {
+        let mismatches = nic_mismatches(staff.nic_number.as_str(), &staff.date_of_birth, &staff.gender);
+        if mismatches.is_empty() {
+            continue;
+        }
+
+        report.push(NicMismatch {
+            staff_id: staff.id.unwrap_or_default(),
+            appointment_number: staff.appointment_number,
+            full_name: staff.full_name,
+            nic_number: staff.nic_number.to_string(),
+            date_of_birth: staff.date_of_birth,
+            gender: staff.gender,
+            mismatches,
+        });
+    }
+
+    Ok(report)
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use chrono::Utc;
+use crate::database::models::StaffNote;
+use crate::database::operations::get_connection;
+
+pub const NOTE_CATEGORIES: [&str; 5] = ["warning", "inquiry", "commendation", "disciplinary_action", "note"];
+
+pub fn create_note_tables(conn: &Connection) -> Result<()> {
+    // Entries are never edited or deleted; a correction is recorded as a new entry
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS staff_notes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            note_date TEXT NOT NULL,
+            category TEXT NOT NULL,
+            reference_number TEXT,
+            description TEXT NOT NULL,
+            outcome TEXT,
+            recorded_by TEXT,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Hash of the passphrase that unlocks the notes log. The notes themselves
+    // are stored unencrypted: the passphrase only gates access to them within
+    // the app, not to the database file.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS notes_access (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            passphrase_hash TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_staff_notes_staff ON staff_notes(staff_id)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Passphrase hash, or None if no passphrase has been set yet
+pub fn get_notes_access_from_db() -> Result<Option<String>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        "SELECT passphrase_hash FROM notes_access WHERE id = 1",
+        [],
+        |row| row.get(0),
+    ).optional()
+}
+
+pub fn set_notes_access_in_db(passphrase_hash: &str) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO notes_access (id, passphrase_hash, updated_at) VALUES (1, ?1, ?2)
+         ON CONFLICT(id) DO UPDATE SET
+            passphrase_hash = excluded.passphrase_hash, updated_at = excluded.updated_at",
+        params![passphrase_hash, now],
+    )?;
+
+    Ok(())
+}
+
+pub fn get_notes_from_db(staff_id: i32) -> Result<Vec<StaffNote>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, note_date, category, reference_number, description, outcome,
+         recorded_by, created_at
+         FROM staff_notes WHERE staff_id = ?1 ORDER BY id"
+    )?;
+
+    let note_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(StaffNote {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            note_date: row.get(2)?,
+            category: row.get(3)?,
+            reference_number: row.get(4)?,
+            description: row.get(5)?,
+            outcome: row.get(6)?,
+            recorded_by: row.get(7)?,
+            created_at: Some(row.get(8)?),
+        })
+    })?;
+
+    let mut notes = Vec::new();
+    for note in note_iter {
+        notes.push(note?);
+    }
+
+    Ok(notes)
+}
+
+pub fn insert_note_in_db(note: &StaffNote) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO staff_notes (
+            staff_id, note_date, category, reference_number, description, outcome, recorded_by, created_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            note.staff_id,
+            note.note_date,
+            note.category,
+            note.reference_number,
+            note.description,
+            note.outcome,
+            note.recorded_by,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
\ No newline at end of file
@@ -0,0 +1,212 @@
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use chrono::Utc;
+use crate::database::models::Office;
+use crate::database::operations::{get_connection, add_column_if_missing};
+use crate::utils::export::Letterhead;
+
+pub fn create_office_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS offices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            code TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            name TEXT NOT NULL,
+            address TEXT,
+            phone TEXT,
+            email TEXT,
+            letterhead_title TEXT NOT NULL,
+            letterhead_subtitle TEXT NOT NULL,
+            is_default INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_offices_default ON offices(is_default) WHERE is_default = 1",
+        [],
+    )?;
+
+    // The office this system was first set up for
+    let count: i32 = conn.query_row("SELECT COUNT(*) FROM offices", [], |row| row.get(0))?;
+    if count == 0 {
+        insert_office(conn, &Office {
+            id: None,
+            code: "VAV".to_string(),
+            name: "Divisional Forest Office - Vavuniya".to_string(),
+            address: Some("Forest Department, Vavuniya, Sri Lanka".to_string()),
+            phone: Some("+94 24 222 2222".to_string()),
+            email: Some("forestoffice.vavuniya@fd.gov.lk".to_string()),
+            letterhead_title: "DIVISIONAL FOREST OFFICE - VAVUNIYA".to_string(),
+            letterhead_subtitle: "Forest Department, Sri Lanka".to_string(),
+            is_default: true,
+            created_at: None,
+            updated_at: None,
+        })?;
+    }
+
+    add_column_if_missing(conn, "staff", "office_id", "INTEGER REFERENCES offices(id)")?;
+
+    conn.execute(
+        "UPDATE staff SET office_id = (SELECT id FROM offices WHERE is_default = 1) WHERE office_id IS NULL",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_staff_office ON staff(office_id)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+const OFFICE_COLUMNS: &str = "id, code, name, address, phone, email, letterhead_title,
+    letterhead_subtitle, is_default, created_at, updated_at";
+
+fn office_from_row(row: &rusqlite::Row) -> Result<Office> {
+    Ok(Office {
+        id: Some(row.get(0)?),
+        code: row.get(1)?,
+        name: row.get(2)?,
+        address: row.get(3)?,
+        phone: row.get(4)?,
+        email: row.get(5)?,
+        letterhead_title: row.get(6)?,
+        letterhead_subtitle: row.get(7)?,
+        is_default: row.get(8)?,
+        created_at: Some(row.get(9)?),
+        updated_at: Some(row.get(10)?),
+    })
+}
+
+fn insert_office(conn: &Connection, office: &Office) -> Result<i64> {
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO offices (
+            code, name, address, phone, email, letterhead_title, letterhead_subtitle,
+            is_default, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            office.code,
+            office.name,
+            office.address,
+            office.phone,
+            office.email,
+            office.letterhead_title,
+            office.letterhead_subtitle,
+            office.is_default,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn get_offices_from_db() -> Result<Vec<Office>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(&format!("SELECT {} FROM offices ORDER BY name", OFFICE_COLUMNS))?;
+
+    let office_iter = stmt.query_map([], office_from_row)?;
+
+    let mut offices = Vec::new();
+    for office in office_iter {
+        offices.push(office?);
+    }
+
+    Ok(offices)
+}
+
+pub fn get_office_from_db(id: i32) -> Result<Option<Office>> {
+    let conn = get_connection()?;
+    conn.query_row(
+        &format!("SELECT {} FROM offices WHERE id = ?1", OFFICE_COLUMNS),
+        params![id],
+        office_from_row,
+    ).optional()
+}
+
+/// Adds an office. It only becomes the default through `set_default_office_in_db`.
+pub fn insert_office_in_db(office: &Office) -> Result<i64> {
+    let conn = get_connection()?;
+    insert_office(&conn, &Office { is_default: false, ..office.clone() })
+}
+
+pub fn update_office_in_db(office: &Office) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE offices SET
+            code = ?1, name = ?2, address = ?3, phone = ?4, email = ?5,
+            letterhead_title = ?6, letterhead_subtitle = ?7, updated_at = ?8
+         WHERE id = ?9",
+        params![
+            office.code,
+            office.name,
+            office.address,
+            office.phone,
+            office.email,
+            office.letterhead_title,
+            office.letterhead_subtitle,
+            now,
+            office.id
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn count_office_staff_from_db(id: i32) -> Result<i32> {
+    let conn = get_connection()?;
+    conn.query_row("SELECT COUNT(*) FROM staff WHERE office_id = ?1", params![id], |row| row.get(0))
+}
+
+pub fn delete_office_from_db(id: i32) -> Result<()> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    tx.execute("DELETE FROM office_cadre WHERE office_id = ?1", params![id])?;
+    tx.execute("DELETE FROM offices WHERE id = ?1", params![id])?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+pub fn set_default_office_in_db(id: i32) -> Result<()> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    tx.execute("UPDATE offices SET is_default = 0 WHERE is_default = 1", [])?;
+    tx.execute("UPDATE offices SET is_default = 1 WHERE id = ?1", params![id])?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+/// Letterhead of an office, or of the default office for reports covering
+/// every office
+pub fn get_letterhead_from_db(office_id: Option<i32>) -> Result<Letterhead> {
+    let conn = get_connection()?;
+    let office = conn.query_row(
+        &format!(
+            "SELECT {} FROM offices WHERE id = ?1 OR (?1 IS NULL AND is_default = 1)",
+            OFFICE_COLUMNS
+        ),
+        params![office_id],
+        office_from_row,
+    )?;
+
+    let contact: Vec<String> = [office.address, office.phone, office.email]
+        .into_iter()
+        .flatten()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    Ok(Letterhead {
+        title: office.letterhead_title,
+        subtitle: office.letterhead_subtitle,
+        contact: if contact.is_empty() { None } else { Some(contact.join(" | ")) },
+    })
+}
\ No newline at end of file
@@ -1,15 +1,106 @@
-use rusqlite::{params, Connection, Result};
-use chrono::Utc;
-use crate::database::models::{Staff, StaffSearch, StaffCount, DesignationCount, GenderCount};
+use rusqlite::{params, Connection, OptionalExtension, Result, Row};
+use std::collections::BTreeMap;
+use chrono::{Local, Utc};
+use crate::database::models::{
+    Staff, StaffSearch, StaffCount, DesignationCount, GenderCount, WorkforceStatistics,
+    CategoryCount, DesignationGenderCount, DesignationSalary, SalaryHistoryEntry
+};
+use crate::database::increments::create_increment_tables;
+use crate::database::designations::create_designation_tables;
+use crate::database::postings::create_posting_tables;
+use crate::database::promotions::{create_promotion_tables, apply_due_promotions};
+use crate::database::leave::create_leave_tables;
+use crate::database::attendance::create_attendance_tables;
+use crate::database::dependents::create_dependent_tables;
+use crate::database::qualifications::create_qualification_tables;
+use crate::database::documents::create_document_tables;
+use crate::database::notes::create_note_tables;
+use crate::database::pension::create_pension_tables;
+use crate::database::service::create_service_tables;
+use crate::database::probation::create_probation_tables;
+use crate::database::reminders::create_reminder_tables;
+use crate::database::offices::create_office_tables;
+use crate::database::duplicates::create_duplicate_tables;
+use crate::database::nic::migrate_nic_numbers;
+use crate::database::salary::{create_salary_tables, insert_salary_history, sync_current_salary, sync_current_salaries};
+use crate::utils::date_utils::{parse_date, format_date, years_between, band_label};
+use crate::utils::nic_converter::Nic;
 
 const DB_PATH: &str = "forest_office_staff.db";
 
+/// Columns read into a `Staff`, in the order expected by `staff_from_row`
+pub const STAFF_COLUMNS: &str = "id, appointment_number, full_name, gender, date_of_birth, age, nic_number,
+    marital_status, address_line1, address_line2, address_line3, contact_number, email,
+    designation, date_of_first_appointment, date_of_retirement, increment_date,
+    salary_code, basic_salary, increment_amount, image_path, created_at, updated_at,
+    designation_id, probation_period_months, probation_end_date, confirmation_date,
+    confirmation_reference, office_id";
+
+/// Restricts a staff query to the office bound to ?1, or to every office when it is NULL
+const OFFICE_SCOPE: &str = "(?1 IS NULL OR office_id = ?1)";
+
+/// Digits of the old format of a stored NIC number, for NICs issued before
+/// 2000 with a zero-padded serial. See `Nic::legacy`.
+const LEGACY_NIC_DIGITS: &str = "CASE WHEN substr(nic_number, 1, 2) = '19' AND substr(nic_number, 8, 1) = '0'
+    THEN substr(nic_number, 3, 5) || substr(nic_number, 9, 4) END";
+
 pub fn get_connection() -> Result<Connection> {
     let conn = Connection::open(DB_PATH)?;
     conn.execute("PRAGMA foreign_keys = ON", [])?;
     Ok(conn)
 }
 
+pub fn staff_from_row(row: &Row) -> Result<Staff> {
+    Ok(Staff {
+        id: Some(row.get(0)?),
+        appointment_number: row.get(1)?,
+        full_name: row.get(2)?,
+        gender: row.get(3)?,
+        date_of_birth: row.get(4)?,
+        age: row.get(5)?,
+        nic_number: Nic::from_stored(row.get(6)?),
+        marital_status: row.get(7)?,
+        address_line1: row.get(8)?,
+        address_line2: row.get(9)?,
+        address_line3: row.get(10)?,
+        contact_number: row.get(11)?,
+        email: row.get(12)?,
+        office_id: row.get(28)?,
+        designation: row.get(13)?,
+        designation_id: row.get(23)?,
+        date_of_first_appointment: row.get(14)?,
+        date_of_retirement: row.get(15)?,
+        increment_date: row.get(16)?,
+        probation_period_months: row.get(24)?,
+        probation_end_date: row.get(25)?,
+        confirmation_date: row.get(26)?,
+        confirmation_reference: row.get(27)?,
+        salary_code: row.get(17)?,
+        basic_salary: row.get(18)?,
+        increment_amount: row.get(19)?,
+        image_path: row.get(20)?,
+        created_at: Some(row.get(21)?),
+        updated_at: Some(row.get(22)?),
+    })
+}
+
+/// Adds a column to an existing table when it is missing, so databases created
+/// by earlier versions pick up new fields on startup
+pub fn add_column_if_missing(conn: &Connection, table: &str, column: &str, definition: &str) -> Result<()> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<String>>>()?
+        .iter()
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])?;
+    }
+
+    Ok(())
+}
+
 pub fn initialize_database() -> Result<()> {
     let conn = get_connection()?;
 
@@ -48,38 +139,57 @@ pub fn initialize_database() -> Result<()> {
         [],
     )?;
 
-    conn.execute(
-        "CREATE INDEX IF NOT EXISTS idx_staff_nic ON staff(nic_number)",
-        [],
-    )?;
-
     conn.execute(
         "CREATE INDEX IF NOT EXISTS idx_staff_designation ON staff(designation)",
         [],
     )?;
 
+    create_probation_tables(&conn)?;
+    create_designation_tables(&conn)?;
+    create_increment_tables(&conn)?;
+    create_salary_tables(&conn)?;
+    create_posting_tables(&conn)?;
+    create_promotion_tables(&conn)?;
+    create_leave_tables(&conn)?;
+    create_attendance_tables(&conn)?;
+    create_dependent_tables(&conn)?;
+    create_qualification_tables(&conn)?;
+    create_document_tables(&conn)?;
+    create_note_tables(&conn)?;
+    create_pension_tables(&conn)?;
+    create_service_tables(&conn)?;
+    create_reminder_tables(&conn)?;
+    create_office_tables(&conn)?;
+    create_duplicate_tables(&conn)?;
+    migrate_nic_numbers(&conn)?;
+    apply_due_promotions(&conn, Local::now().date_naive())?;
+    sync_current_salaries(&conn, Local::now().date_naive())?;
+
     Ok(())
 }
 
 pub fn insert_staff(staff: &Staff) -> Result<i64> {
-    let conn = get_connection()?;
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
     let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-    conn.execute(
+    tx.execute(
         "INSERT INTO staff (
             appointment_number, full_name, gender, date_of_birth, age, nic_number,
             marital_status, address_line1, address_line2, address_line3,
             contact_number, email, designation, date_of_first_appointment,
             date_of_retirement, increment_date, salary_code, basic_salary,
-            increment_amount, image_path, created_at, updated_at
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)",
+            increment_amount, image_path, created_at, updated_at, designation_id,
+            probation_period_months, probation_end_date, office_id
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25,
+            COALESCE(?26, (SELECT id FROM offices WHERE is_default = 1)))",
         params![
             staff.appointment_number,
             staff.full_name,
             staff.gender,
             staff.date_of_birth,
             staff.age,
-            staff.nic_number,
+            staff.nic_number.as_str(),
             staff.marital_status,
             staff.address_line1,
             staff.address_line2,
@@ -95,50 +205,39 @@ pub fn insert_staff(staff: &Staff) -> Result<i64> {
             staff.increment_amount,
             staff.image_path,
             now,
-            now
+            now,
+            staff.designation_id,
+            staff.probation_period_months,
+            staff.probation_end_date,
+            staff.office_id
         ],
     )?;
+    let id = tx.last_insert_rowid();
+
+    // Opening salary history entry, so later changes have something to follow
+    insert_salary_history(&tx, &SalaryHistoryEntry {
+        id: None,
+        staff_id: id as i32,
+        effective_date: staff.date_of_first_appointment.clone(),
+        salary_code: staff.salary_code.clone(),
+        basic_salary: staff.basic_salary,
+        increment_amount: staff.increment_amount,
+        reason: "appointment".to_string(),
+        reference_number: None,
+        remarks: None,
+        created_at: None,
+        updated_at: None,
+    })?;
 
-    Ok(conn.last_insert_rowid())
+    tx.commit()?;
+    Ok(id)
 }
 
 pub fn get_all_staff_from_db() -> Result<Vec<Staff>> {
     let conn = get_connection()?;
-    let mut stmt = conn.prepare(
-        "SELECT id, appointment_number, full_name, gender, date_of_birth, age, nic_number,
-         marital_status, address_line1, address_line2, address_line3, contact_number, email,
-         designation, date_of_first_appointment, date_of_retirement, increment_date,
-         salary_code, basic_salary, increment_amount, image_path, created_at, updated_at
-         FROM staff ORDER BY full_name"
-    )?;
+    let mut stmt = conn.prepare(&format!("SELECT {} FROM staff ORDER BY full_name", STAFF_COLUMNS))?;
 
-    let staff_iter = stmt.query_map([], |row| {
-        Ok(Staff {
-            id: Some(row.get(0)?),
-            appointment_number: row.get(1)?,
-            full_name: row.get(2)?,
-            gender: row.get(3)?,
-            date_of_birth: row.get(4)?,
-            age: row.get(5)?,
-            nic_number: row.get(6)?,
-            marital_status: row.get(7)?,
-            address_line1: row.get(8)?,
-            address_line2: row.get(9)?,
-            address_line3: row.get(10)?,
-            contact_number: row.get(11)?,
-            email: row.get(12)?,
-            designation: row.get(13)?,
-            date_of_first_appointment: row.get(14)?,
-            date_of_retirement: row.get(15)?,
-            increment_date: row.get(16)?,
-            salary_code: row.get(17)?,
-            basic_salary: row.get(18)?,
-            increment_amount: row.get(19)?,
-            image_path: row.get(20)?,
-            created_at: Some(row.get(21)?),
-            updated_at: Some(row.get(22)?),
-        })
-    })?;
+    let staff_iter = stmt.query_map([], staff_from_row)?;
 
     let mut staff = Vec::new();
     for s in staff_iter {
@@ -150,41 +249,9 @@ pub fn get_all_staff_from_db() -> Result<Vec<Staff>> {
 
 pub fn get_staff_by_id_from_db(id: i32) -> Result<Option<Staff>> {
     let conn = get_connection()?;
-    let mut stmt = conn.prepare(
-        "SELECT id, appointment_number, full_name, gender, date_of_birth, age, nic_number,
-         marital_status, address_line1, address_line2, address_line3, contact_number, email,
-         designation, date_of_first_appointment, date_of_retirement, increment_date,
-         salary_code, basic_salary, increment_amount, image_path, created_at, updated_at
-         FROM staff WHERE id = ?1"
-    )?;
+    let mut stmt = conn.prepare(&format!("SELECT {} FROM staff WHERE id = ?1", STAFF_COLUMNS))?;
 
-    let mut rows = stmt.query_map(params![id], |row| {
-        Ok(Staff {
-            id: Some(row.get(0)?),
-            appointment_number: row.get(1)?,
-            full_name: row.get(2)?,
-            gender: row.get(3)?,
-            date_of_birth: row.get(4)?,
-            age: row.get(5)?,
-            nic_number: row.get(6)?,
-            marital_status: row.get(7)?,
-            address_line1: row.get(8)?,
-            address_line2: row.get(9)?,
-            address_line3: row.get(10)?,
-            contact_number: row.get(11)?,
-            email: row.get(12)?,
-            designation: row.get(13)?,
-            date_of_first_appointment: row.get(14)?,
-            date_of_retirement: row.get(15)?,
-            increment_date: row.get(16)?,
-            salary_code: row.get(17)?,
-            basic_salary: row.get(18)?,
-            increment_amount: row.get(19)?,
-            image_path: row.get(20)?,
-            created_at: Some(row.get(21)?),
-            updated_at: Some(row.get(22)?),
-        })
-    })?;
+    let mut rows = stmt.query_map(params![id], staff_from_row)?;
 
     match rows.next() {
         Some(staff) => Ok(Some(staff?)),
@@ -193,25 +260,36 @@ pub fn get_staff_by_id_from_db(id: i32) -> Result<Option<Staff>> {
 }
 
 pub fn update_staff_in_db(staff: &Staff) -> Result<()> {
-    let conn = get_connection()?;
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
     let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-    conn.execute(
+    let previous: Option<(String, f64, f64)> = tx.query_row(
+        "SELECT salary_code, basic_salary, increment_amount FROM staff WHERE id = ?1",
+        params![staff.id],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ).optional()?;
+
+    // Probation details and office are kept when left out of the update;
+    // confirmation is only ever recorded through `confirm_probation_in_db`
+    tx.execute(
         "UPDATE staff SET
             appointment_number = ?1, full_name = ?2, gender = ?3, date_of_birth = ?4,
             age = ?5, nic_number = ?6, marital_status = ?7, address_line1 = ?8,
             address_line2 = ?9, address_line3 = ?10, contact_number = ?11, email = ?12,
             designation = ?13, date_of_first_appointment = ?14, date_of_retirement = ?15,
-            increment_date = ?16, salary_code = ?17, basic_salary = ?18,
-            increment_amount = ?19, image_path = ?20, updated_at = ?21
-         WHERE id = ?22",
+            increment_date = ?16, image_path = ?17, updated_at = ?18, designation_id = ?19,
+            probation_period_months = COALESCE(?20, probation_period_months),
+            probation_end_date = COALESCE(?21, probation_end_date),
+            office_id = COALESCE(?22, office_id)
+         WHERE id = ?23",
         params![
             staff.appointment_number,
             staff.full_name,
             staff.gender,
             staff.date_of_birth,
             staff.age,
-            staff.nic_number,
+            staff.nic_number.as_str(),
             staff.marital_status,
             staff.address_line1,
             staff.address_line2,
@@ -222,15 +300,41 @@ pub fn update_staff_in_db(staff: &Staff) -> Result<()> {
             staff.date_of_first_appointment,
             staff.date_of_retirement,
             staff.increment_date,
-            staff.salary_code,
-            staff.basic_salary,
-            staff.increment_amount,
             staff.image_path,
             now,
+            staff.designation_id,
+            staff.probation_period_months,
+            staff.probation_end_date,
+            staff.office_id,
             staff.id
         ],
     )?;
 
+    // Salary edited directly on the record is recorded as a revision from
+    // today and applied through the history like any other change
+    if let (Some(id), Some((salary_code, basic_salary, increment_amount))) = (staff.id, previous) {
+        if salary_code != staff.salary_code
+            || basic_salary != staff.basic_salary
+            || increment_amount != staff.increment_amount
+        {
+            insert_salary_history(&tx, &SalaryHistoryEntry {
+                id: None,
+                staff_id: id,
+                effective_date: format_date(Local::now().date_naive()),
+                salary_code: staff.salary_code.clone(),
+                basic_salary: staff.basic_salary,
+                increment_amount: staff.increment_amount,
+                reason: "revision".to_string(),
+                reference_number: None,
+                remarks: Some("Updated on staff record".to_string()),
+                created_at: None,
+                updated_at: None,
+            })?;
+            sync_current_salary(&tx, id, Local::now().date_naive())?;
+        }
+    }
+
+    tx.commit()?;
     Ok(())
 }
 
@@ -242,13 +346,7 @@ pub fn delete_staff_from_db(id: i32) -> Result<()> {
 
 pub fn search_staff_in_db(search: &StaffSearch) -> Result<Vec<Staff>> {
     let conn = get_connection()?;
-    let mut query = String::from(
-        "SELECT id, appointment_number, full_name, gender, date_of_birth, age, nic_number,
-         marital_status, address_line1, address_line2, address_line3, contact_number, email,
-         designation, date_of_first_appointment, date_of_retirement, increment_date,
-         salary_code, basic_salary, increment_amount, image_path, created_at, updated_at
-         FROM staff WHERE 1=1"
-    );
+    let mut query = format!("SELECT {} FROM staff WHERE 1=1", STAFF_COLUMNS);
 
     let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
 
@@ -288,12 +386,54 @@ pub fn search_staff_in_db(search: &StaffSearch) -> Result<Vec<Staff>> {
     }
 
     if let Some(ref nic) = search.nic_number {
+        let nic = nic.trim();
         if !nic.is_empty() {
-            query.push_str(&format!(" AND nic_number LIKE ?{}", params.len() + 1));
-            params.push(Box::new(format!("%{}%", nic)));
+            match nic.parse::<Nic>() {
+                // A complete NIC in either format finds its record exactly
+                Ok(nic) => {
+                    query.push_str(&format!(" AND nic_number = ?{}", params.len() + 1));
+                    params.push(Box::new(nic.to_string()));
+                }
+                // Part of a NIC is looked for in both the new and the old format
+                Err(_) => {
+                    let legacy_digits = nic.trim_end_matches(&['V', 'v', 'X', 'x'][..]);
+                    query.push_str(&format!(
+                        " AND (nic_number LIKE ?{} OR {} LIKE ?{})",
+                        params.len() + 1, LEGACY_NIC_DIGITS, params.len() + 2
+                    ));
+                    params.push(Box::new(format!("%{}%", nic)));
+                    params.push(Box::new(format!("%{}%", legacy_digits)));
+                }
+            }
+        }
+    }
+
+    if let Some(ref qualification) = search.qualification {
+        if !qualification.is_empty() {
+            query.push_str(&format!(
+                " AND EXISTS (SELECT 1 FROM qualifications q WHERE q.staff_id = staff.id AND q.title LIKE ?{})",
+                params.len() + 1
+            ));
+            params.push(Box::new(format!("%{}%", qualification)));
         }
     }
 
+    if let Some(ref training) = search.training {
+        if !training.is_empty() {
+            query.push_str(&format!(
+                " AND EXISTS (SELECT 1 FROM training_courses t WHERE t.staff_id = staff.id
+                  AND t.completed = 1 AND t.course_name LIKE ?{})",
+                params.len() + 1
+            ));
+            params.push(Box::new(format!("%{}%", training)));
+        }
+    }
+
+    if let Some(office_id) = search.office_id {
+        query.push_str(&format!(" AND office_id = ?{}", params.len() + 1));
+        params.push(Box::new(office_id));
+    }
+
     if let Some(age_min) = search.age_min {
         query.push_str(&format!(" AND age >= ?{}", params.len() + 1));
         params.push(Box::new(age_min));
@@ -309,33 +449,7 @@ pub fn search_staff_in_db(search: &StaffSearch) -> Result<Vec<Staff>> {
     let mut stmt = conn.prepare(&query)?;
     let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
 
-    let staff_iter = stmt.query_map(param_refs.as_slice(), |row| {
-        Ok(Staff {
-            id: Some(row.get(0)?),
-            appointment_number: row.get(1)?,
-            full_name: row.get(2)?,
-            gender: row.get(3)?,
-            date_of_birth: row.get(4)?,
-            age: row.get(5)?,
-            nic_number: row.get(6)?,
-            marital_status: row.get(7)?,
-            address_line1: row.get(8)?,
-            address_line2: row.get(9)?,
-            address_line3: row.get(10)?,
-            contact_number: row.get(11)?,
-            email: row.get(12)?,
-            designation: row.get(13)?,
-            date_of_first_appointment: row.get(14)?,
-            date_of_retirement: row.get(15)?,
-            increment_date: row.get(16)?,
-            salary_code: row.get(17)?,
-            basic_salary: row.get(18)?,
-            increment_amount: row.get(19)?,
-            image_path: row.get(20)?,
-            created_at: Some(row.get(21)?),
-            updated_at: Some(row.get(22)?),
-        })
-    })?;
+    let staff_iter = stmt.query_map(param_refs.as_slice(), staff_from_row)?;
 
     let mut staff = Vec::new();
     for s in staff_iter {
@@ -345,15 +459,23 @@ pub fn search_staff_in_db(search: &StaffSearch) -> Result<Vec<Staff>> {
     Ok(staff)
 }
 
-pub fn get_staff_statistics() -> Result<StaffCount> {
+/// Staff counts for one office, or for every office when `office_id` is None
+pub fn get_staff_statistics(office_id: Option<i32>) -> Result<StaffCount> {
     let conn = get_connection()?;
 
     // Get total count
-    let total: i32 = conn.query_row("SELECT COUNT(*) FROM staff", [], |row| row.get(0))?;
+    let total: i32 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM staff WHERE {}", OFFICE_SCOPE),
+        params![office_id],
+        |row| row.get(0),
+    )?;
 
     // Get count by designation
-    let mut designation_stmt = conn.prepare("SELECT designation, COUNT(*) FROM staff GROUP BY designation ORDER BY designation")?;
-    let designation_iter = designation_stmt.query_map([], |row| {
+    let mut designation_stmt = conn.prepare(&format!(
+        "SELECT designation, COUNT(*) FROM staff WHERE {} GROUP BY designation ORDER BY designation",
+        OFFICE_SCOPE
+    ))?;
+    let designation_iter = designation_stmt.query_map(params![office_id], |row| {
         Ok(DesignationCount {
             designation: row.get(0)?,
             count: row.get(1)?,
@@ -366,8 +488,11 @@ pub fn get_staff_statistics() -> Result<StaffCount> {
     }
 
     // Get count by gender
-    let mut gender_stmt = conn.prepare("SELECT gender, COUNT(*) FROM staff GROUP BY gender ORDER BY gender")?;
-    let gender_iter = gender_stmt.query_map([], |row| {
+    let mut gender_stmt = conn.prepare(&format!(
+        "SELECT gender, COUNT(*) FROM staff WHERE {} GROUP BY gender ORDER BY gender",
+        OFFICE_SCOPE
+    ))?;
+    let gender_iter = gender_stmt.query_map(params![office_id], |row| {
         Ok(GenderCount {
             gender: row.get(0)?,
             count: row.get(1)?,
@@ -384,4 +509,147 @@ pub fn get_staff_statistics() -> Result<StaffCount> {
         by_designation,
         by_gender,
     })
+}
+
+/// Workforce figures for one office, or for every office when `office_id` is None
+pub fn get_workforce_statistics(office_id: Option<i32>) -> Result<WorkforceStatistics> {
+    let conn = get_connection()?;
+
+    let total: i32 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM staff WHERE {}", OFFICE_SCOPE),
+        params![office_id],
+        |row| row.get(0),
+    )?;
+
+    let by_salary_code = count_by_column(&conn, "salary_code", office_id)?;
+    let by_marital_status = count_by_column(&conn, "marital_status", office_id)?;
+
+    // Age and service bands are computed from the stored dates rather than the
+    // `age` column, which is only refreshed when a record is saved
+    let today = Local::now().date_naive();
+    let mut age_bands: BTreeMap<i32, i32> = BTreeMap::new();
+    let mut service_bands: BTreeMap<i32, i32> = BTreeMap::new();
+    let mut unknown_age = 0;
+    let mut unknown_service = 0;
+
+    let mut dates_stmt = conn.prepare(&format!(
+        "SELECT date_of_birth, date_of_first_appointment FROM staff WHERE {}",
+        OFFICE_SCOPE
+    ))?;
+    let dates_iter = dates_stmt.query_map(params![office_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    for dates in dates_iter {
+        let (date_of_birth, date_of_first_appointment) = dates?;
+
+        match parse_date(&date_of_birth) {
+            Some(dob) => *age_bands.entry(years_between(dob, today).div_euclid(5)).or_insert(0) += 1,
+            None => unknown_age += 1,
+        }
+
+        match parse_date(&date_of_first_appointment) {
+            Some(doa) => {
+                let service = years_between(doa, today).max(0);
+                *service_bands.entry(service.div_euclid(5)).or_insert(0) += 1;
+            }
+            None => unknown_service += 1,
+        }
+    }
+
+    let by_age_band = bands_to_counts(age_bands, unknown_age);
+    let by_service_band = bands_to_counts(service_bands, unknown_service);
+
+    // Cross-tab of designation by gender
+    let mut gender_stmt = conn.prepare(&format!(
+        "SELECT designation,
+                SUM(CASE WHEN gender = 'Male' THEN 1 ELSE 0 END),
+                SUM(CASE WHEN gender = 'Female' THEN 1 ELSE 0 END),
+                COUNT(*)
+         FROM staff WHERE {} GROUP BY designation ORDER BY designation",
+        OFFICE_SCOPE
+    ))?;
+    let gender_iter = gender_stmt.query_map(params![office_id], |row| {
+        Ok(DesignationGenderCount {
+            designation: row.get(0)?,
+            male: row.get(1)?,
+            female: row.get(2)?,
+            total: row.get(3)?,
+        })
+    })?;
+
+    let mut designation_by_gender = Vec::new();
+    for count in gender_iter {
+        designation_by_gender.push(count?);
+    }
+
+    // Basic salary figures per designation
+    let mut salary_stmt = conn.prepare(&format!(
+        "SELECT designation, COUNT(*), AVG(basic_salary), MIN(basic_salary), MAX(basic_salary)
+         FROM staff WHERE {} GROUP BY designation ORDER BY designation",
+        OFFICE_SCOPE
+    ))?;
+    let salary_iter = salary_stmt.query_map(params![office_id], |row| {
+        Ok(DesignationSalary {
+            designation: row.get(0)?,
+            count: row.get(1)?,
+            average_basic_salary: row.get(2)?,
+            min_basic_salary: row.get(3)?,
+            max_basic_salary: row.get(4)?,
+        })
+    })?;
+
+    let mut salary_by_designation = Vec::new();
+    for salary in salary_iter {
+        salary_by_designation.push(salary?);
+    }
+
+    Ok(WorkforceStatistics {
+        total,
+        by_salary_code,
+        by_marital_status,
+        by_age_band,
+        by_service_band,
+        designation_by_gender,
+        salary_by_designation,
+    })
+}
+
+fn count_by_column(conn: &Connection, column: &str, office_id: Option<i32>) -> Result<Vec<CategoryCount>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {0}, COUNT(*) FROM staff WHERE {1} GROUP BY {0} ORDER BY {0}",
+        column, OFFICE_SCOPE
+    ))?;
+    let count_iter = stmt.query_map(params![office_id], |row| {
+        Ok(CategoryCount {
+            label: row.get(0)?,
+            count: row.get(1)?,
+        })
+    })?;
+
+    let mut counts = Vec::new();
+    for count in count_iter {
+        counts.push(count?);
+    }
+
+    Ok(counts)
+}
+
+fn bands_to_counts(bands: BTreeMap<i32, i32>, unknown: i32) -> Vec<CategoryCount> {
+    let mut counts: Vec<CategoryCount> = bands
+        .into_iter()
+        .map(|(band, count)| CategoryCount {
+            label: band_label(band * 5, 5),
+            count,
+        })
+        .collect();
+
+    if unknown > 0 {
+        counts.push(CategoryCount {
+            label: "Unknown".to_string(),
+            count: unknown,
+        });
+    }
+
+    counts
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{Days, NaiveDate};
+use crate::database::models::{PensionRules, PensionEstimate};
+use crate::database::operations::get_connection;
+use crate::database::reports::retirement_date_of;
+use crate::database::service::{service_deductions, Periods};
+use crate::utils::date_utils::{parse_date, format_date, add_years, months_between, days_covered};
+use crate::utils::pension::estimate_pension;
+
+pub fn create_pension_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS pension_rules (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            minimum_service_years INTEGER NOT NULL,
+            base_percentage REAL NOT NULL,
+            percentage_per_year REAL NOT NULL,
+            maximum_percentage REAL NOT NULL,
+            gratuity_months REAL NOT NULL,
+            commutation_reduction REAL NOT NULL,
+            commutation_years INTEGER NOT NULL,
+            short_service_gratuity_months REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    // Defaults follow the Minutes on Pensions as generally applied; check them
+    // against the latest Pensions Department circulars and adjust as needed
+    conn.execute(
+        "INSERT OR IGNORE INTO pension_rules (
+            id, minimum_service_years, base_percentage, percentage_per_year, maximum_percentage,
+            gratuity_months, commutation_reduction, commutation_years, short_service_gratuity_months
+        ) VALUES (1, 10, 60, 1.5, 90, 24, 50, 10, 0.5)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+fn pension_rules(conn: &Connection) -> Result<PensionRules> {
+    conn.query_row(
+        "SELECT minimum_service_years, base_percentage, percentage_per_year, maximum_percentage,
+         gratuity_months, commutation_reduction, commutation_years, short_service_gratuity_months
+         FROM pension_rules WHERE id = 1",
+        [],
+        |row| {
+            Ok(PensionRules {
+                minimum_service_years: row.get(0)?,
+                base_percentage: row.get(1)?,
+                percentage_per_year: row.get(2)?,
+                maximum_percentage: row.get(3)?,
+                gratuity_months: row.get(4)?,
+                commutation_reduction: row.get(5)?,
+                commutation_years: row.get(6)?,
+                short_service_gratuity_months: row.get(7)?,
+            })
+        },
+    )
+}
+
+pub fn get_pension_rules_from_db() -> Result<PensionRules> {
+    let conn = get_connection()?;
+    pension_rules(&conn)
+}
+
+pub fn update_pension_rules_in_db(rules: &PensionRules) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "UPDATE pension_rules SET
+            minimum_service_years = ?1, base_percentage = ?2, percentage_per_year = ?3,
+            maximum_percentage = ?4, gratuity_months = ?5, commutation_reduction = ?6,
+            commutation_years = ?7, short_service_gratuity_months = ?8
+         WHERE id = 1",
+        params![
+            rules.minimum_service_years,
+            rules.base_percentage,
+            rules.percentage_per_year,
+            rules.maximum_percentage,
+            rules.gratuity_months,
+            rules.commutation_reduction,
+            rules.commutation_years,
+            rules.short_service_gratuity_months
+        ],
+    )?;
+    Ok(())
+}
+
+/// Pension estimate for a staff member, using the recorded retirement date
+/// unless another one is given. None if the staff member does not exist or
+/// their dates cannot be read.
+pub fn get_pension_estimate_from_db(staff_id: i32, retirement_date: Option<NaiveDate>) -> Result<Option<PensionEstimate>> {
+    let conn = get_connection()?;
+    let rules = pension_rules(&conn)?;
+
+    let staff = conn.query_row(
+        "SELECT appointment_number, full_name, designation, date_of_first_appointment,
+         date_of_retirement, date_of_birth, basic_salary
+         FROM staff WHERE id = ?1",
+        params![staff_id],
+        |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, f64>(6)?,
+            ))
+        },
+    );
+
+    let (appointment_number, full_name, designation, first_appointment, date_of_retirement, date_of_birth, basic_salary) =
+        match staff {
+            Ok(staff) => staff,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+    let appointed = match parse_date(&first_appointment) {
+        Some(date) => date,
+        None => return Ok(None),
+    };
+    let retirement = match retirement_date.or_else(|| retirement_date_of(&date_of_retirement, &date_of_birth)) {
+        Some(date) => date,
+        None => return Ok(None),
+    };
+
+    // Breaks in service and no-pay leave do not count towards pension
+    let (breaks, no_pay) = service_deductions(&conn, staff_id)?;
+    let deductions: Periods = breaks.into_iter().chain(no_pay).collect();
+    let deducted_days = days_covered(&deductions, appointed, retirement) as u64;
+    let net_end = retirement.checked_sub_days(Days::new(deducted_days)).unwrap_or(appointed);
+
+    let service = months_between(appointed, net_end).max(0);
+    let figures = estimate_pension(&rules, service, basic_salary);
+
+    Ok(Some(PensionEstimate {
+        staff_id,
+        appointment_number,
+        full_name,
+        designation,
+        date_of_first_appointment: format_date(appointed),
+        retirement_date: format_date(retirement),
+        service_years: service / 12,
+        service_months: service % 12,
+        last_basic_salary: basic_salary,
+        pensionable: figures.pensionable,
+        pension_percentage: figures.pension_percentage,
+        monthly_pension: figures.monthly_pension,
+        gratuity: figures.gratuity,
+        reduced_monthly_pension: figures.reduced_monthly_pension,
+        full_pension_from: if figures.pensionable {
+            add_years(retirement, rules.commutation_years).map(format_date)
+        } else {
+            None
+        },
+    }))
+}
\ No newline at end of file
@@ -0,0 +1,189 @@
+use rusqlite::{params, Connection, Result};
+use chrono::Utc;
+use crate::database::models::{Posting, StationStaff};
+use crate::database::operations::get_connection;
+
+pub const TRANSFER_TYPES: [&str; 7] = [
+    "appointment", "annual", "request", "exigency", "promotion", "disciplinary", "attachment",
+];
+
+pub fn create_posting_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS postings (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            station TEXT NOT NULL COLLATE NOCASE,
+            station_type TEXT,
+            from_date TEXT NOT NULL,
+            to_date TEXT,
+            order_reference TEXT,
+            transfer_type TEXT NOT NULL,
+            remarks TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_postings_staff ON postings(staff_id)",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_postings_station ON postings(station)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+fn posting_from_row(row: &rusqlite::Row) -> Result<Posting> {
+    Ok(Posting {
+        id: Some(row.get(0)?),
+        staff_id: row.get(1)?,
+        station: row.get(2)?,
+        station_type: row.get(3)?,
+        from_date: row.get(4)?,
+        to_date: row.get(5)?,
+        order_reference: row.get(6)?,
+        transfer_type: row.get(7)?,
+        remarks: row.get(8)?,
+        created_at: Some(row.get(9)?),
+        updated_at: Some(row.get(10)?),
+    })
+}
+
+fn insert_posting(conn: &Connection, posting: &Posting) -> Result<i64> {
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO postings (
+            staff_id, station, station_type, from_date, to_date, order_reference,
+            transfer_type, remarks, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            posting.staff_id,
+            posting.station,
+            posting.station_type,
+            posting.from_date,
+            posting.to_date,
+            posting.order_reference,
+            posting.transfer_type,
+            posting.remarks,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn get_postings_from_db(staff_id: i32) -> Result<Vec<Posting>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, station, station_type, from_date, to_date, order_reference,
+         transfer_type, remarks, created_at, updated_at
+         FROM postings WHERE staff_id = ?1"
+    )?;
+
+    let posting_iter = stmt.query_map(params![staff_id], posting_from_row)?;
+
+    let mut postings = Vec::new();
+    for posting in posting_iter {
+        postings.push(posting?);
+    }
+
+    Ok(postings)
+}
+
+pub fn insert_posting_in_db(posting: &Posting) -> Result<i64> {
+    let conn = get_connection()?;
+    insert_posting(&conn, posting)
+}
+
+/// Closes the current posting, if any, and opens the new one in a single transaction
+pub fn record_transfer_in_db(close: Option<(i32, String)>, posting: &Posting) -> Result<i64> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    if let Some((id, to_date)) = close {
+        let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        tx.execute(
+            "UPDATE postings SET to_date = ?1, updated_at = ?2 WHERE id = ?3",
+            params![to_date, now, id],
+        )?;
+    }
+
+    let id = insert_posting(&tx, posting)?;
+
+    tx.commit()?;
+    Ok(id)
+}
+
+pub fn update_posting_in_db(posting: &Posting) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE postings SET
+            station = ?1, station_type = ?2, from_date = ?3, to_date = ?4,
+            order_reference = ?5, transfer_type = ?6, remarks = ?7, updated_at = ?8
+         WHERE id = ?9 AND staff_id = ?10",
+        params![
+            posting.station,
+            posting.station_type,
+            posting.from_date,
+            posting.to_date,
+            posting.order_reference,
+            posting.transfer_type,
+            posting.remarks,
+            now,
+            posting.id,
+            posting.staff_id
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_posting_from_db(id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM postings WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+/// All postings to a station with the staff member's details. Callers decide
+/// which of them are current, since dates are stored as dd-MM-yyyy text.
+pub fn get_station_postings_from_db(station: &str) -> Result<Vec<StationStaff>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.appointment_number, s.full_name, s.designation,
+         p.id, p.station, p.from_date, p.to_date, p.order_reference
+         FROM postings p
+         JOIN staff s ON s.id = p.staff_id
+         WHERE p.station = ?1
+         ORDER BY s.full_name"
+    )?;
+
+    let posting_iter = stmt.query_map(params![station.trim()], |row| {
+        Ok(StationStaff {
+            staff_id: row.get(0)?,
+            appointment_number: row.get(1)?,
+            full_name: row.get(2)?,
+            designation: row.get(3)?,
+            posting_id: row.get(4)?,
+            station: row.get(5)?,
+            from_date: row.get(6)?,
+            to_date: row.get(7)?,
+            order_reference: row.get(8)?,
+        })
+    })?;
+
+    let mut postings = Vec::new();
+    for posting in posting_iter {
+        postings.push(posting?);
+    }
+
+    Ok(postings)
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{NaiveDate, Utc};
+use crate::database::models::ProbationDue;
+use crate::database::operations::{get_connection, add_column_if_missing};
+use crate::utils::date_utils::parse_date;
+
+pub fn create_probation_tables(conn: &Connection) -> Result<()> {
+    add_column_if_missing(conn, "staff", "probation_period_months", "INTEGER")?;
+    add_column_if_missing(conn, "staff", "probation_end_date", "TEXT")?;
+    add_column_if_missing(conn, "staff", "confirmation_date", "TEXT")?;
+    add_column_if_missing(conn, "staff", "confirmation_reference", "TEXT")?;
+    Ok(())
+}
+
+/// Unconfirmed staff whose probation ends on or before `until`, including
+/// those whose probation has already lapsed
+pub fn get_probation_due_from_db(today: NaiveDate, until: NaiveDate) -> Result<Vec<ProbationDue>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, appointment_number, full_name, designation, date_of_first_appointment, probation_end_date
+         FROM staff
+         WHERE probation_end_date IS NOT NULL AND confirmation_date IS NULL"
+    )?;
+
+    let staff_iter = stmt.query_map([], |row| {
+        Ok(ProbationDue {
+            staff_id: row.get(0)?,
+            appointment_number: row.get(1)?,
+            full_name: row.get(2)?,
+            designation: row.get(3)?,
+            date_of_first_appointment: row.get(4)?,
+            probation_end_date: row.get(5)?,
+            days_remaining: 0,
+            lapsed: false,
+        })
+    })?;
+
+    let mut due = Vec::new();
+    for entry in staff_iter {
+        let mut entry = entry?;
+        let end = match parse_date(&entry.probation_end_date) {
+            Some(end) if end <= until => end,
+            _ => continue,
+        };
+
+        entry.days_remaining = (end - today).num_days();
+        entry.lapsed = end < today;
+        due.push((end, entry));
+    }
+
+    due.sort_by_key(|(end, _)| *end);
+    Ok(due.into_iter().map(|(_, entry)| entry).collect())
+}
+
+pub fn confirm_probation_in_db(staff_id: i32, confirmation_date: &str, reference: &str) -> Result<usize> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE staff SET confirmation_date = ?1, confirmation_reference = ?2, updated_at = ?3 WHERE id = ?4",
+        params![confirmation_date, reference, now, staff_id],
+    )
+}
+
+/// Removes the probation period and end date from a staff record. Updates
+/// to the staff record keep them when left out, so this is the only way to
+/// take off a probation entered by mistake.
+pub fn clear_probation_in_db(staff_id: i32) -> Result<usize> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE staff SET probation_period_months = NULL, probation_end_date = NULL, updated_at = ?1 WHERE id = ?2",
+        params![now, staff_id],
+    )
+}
\ No newline at end of file
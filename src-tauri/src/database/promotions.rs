@@ -0,0 +1,180 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{Local, NaiveDate, Utc};
+use crate::database::models::{Promotion, SalaryHistoryEntry, Staff};
+use crate::database::operations::{get_connection, add_column_if_missing};
+use crate::database::salary::{insert_salary_history, sync_current_salary};
+use crate::utils::date_utils::parse_date;
+
+pub fn create_promotion_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS promotions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            from_designation TEXT NOT NULL,
+            from_designation_id INTEGER REFERENCES designations(id),
+            to_designation TEXT NOT NULL,
+            to_designation_id INTEGER REFERENCES designations(id),
+            from_salary_code TEXT NOT NULL,
+            to_salary_code TEXT NOT NULL,
+            basic_salary REAL NOT NULL,
+            effective_date TEXT NOT NULL,
+            approval_reference TEXT NOT NULL,
+            remarks TEXT,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_promotions_staff ON promotions(staff_id)",
+        [],
+    )?;
+
+    // Promotions recorded before this column existed were applied at once
+    add_column_if_missing(conn, "promotions", "applied", "INTEGER NOT NULL DEFAULT 1")?;
+
+    Ok(())
+}
+
+/// Moves staff to the designation of each promotion that has come into
+/// effect on `today` and not been applied yet, in order of effective date.
+/// The salary follows separately through the promotion's history entry.
+pub fn apply_due_promotions(conn: &Connection, today: NaiveDate) -> Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, to_designation, to_designation_id, effective_date
+         FROM promotions WHERE applied = 0 ORDER BY id"
+    )?;
+    let pending = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, i32>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<i32>>(3)?,
+            row.get::<_, String>(4)?,
+        ))
+    })?.collect::<Result<Vec<_>>>()?;
+
+    let mut due: Vec<_> = pending
+        .into_iter()
+        .filter_map(|(id, staff_id, designation, designation_id, effective_date)| {
+            parse_date(&effective_date)
+                .filter(|date| *date <= today)
+                .map(|date| (date, id, staff_id, designation, designation_id))
+        })
+        .collect();
+    due.sort_by_key(|(date, id, ..)| (*date, *id));
+
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    for (_, id, staff_id, designation, designation_id) in due {
+        conn.execute(
+            "UPDATE staff SET designation = ?1, designation_id = ?2, updated_at = ?3 WHERE id = ?4",
+            params![designation, designation_id, now, staff_id],
+        )?;
+        conn.execute("UPDATE promotions SET applied = 1 WHERE id = ?1", params![id])?;
+    }
+
+    Ok(())
+}
+
+pub fn apply_due_promotions_in_db(today: NaiveDate) -> Result<()> {
+    let conn = get_connection()?;
+    apply_due_promotions(&conn, today)
+}
+
+/// Records the promotion and its salary history entry in one transaction.
+/// The staff record moves to the new designation and salary on the
+/// effective date, straight away when that date has already passed.
+pub fn promote_staff_in_db(promotion: &Promotion, promoted: &Staff) -> Result<i64> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    tx.execute(
+        "INSERT INTO promotions (
+            staff_id, from_designation, from_designation_id, to_designation, to_designation_id,
+            from_salary_code, to_salary_code, basic_salary, effective_date, approval_reference,
+            remarks, created_at, applied
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, 0)",
+        params![
+            promotion.staff_id,
+            promotion.from_designation,
+            promotion.from_designation_id,
+            promotion.to_designation,
+            promotion.to_designation_id,
+            promotion.from_salary_code,
+            promotion.to_salary_code,
+            promotion.basic_salary,
+            promotion.effective_date,
+            promotion.approval_reference,
+            promotion.remarks,
+            now
+        ],
+    )?;
+    let id = tx.last_insert_rowid();
+
+    insert_salary_history(&tx, &SalaryHistoryEntry {
+        id: None,
+        staff_id: promotion.staff_id,
+        effective_date: promotion.effective_date.clone(),
+        salary_code: promoted.salary_code.clone(),
+        basic_salary: promoted.basic_salary,
+        increment_amount: promoted.increment_amount,
+        reason: "promotion".to_string(),
+        reference_number: Some(promotion.approval_reference.clone()),
+        remarks: Some(format!("Promoted to {}", promotion.to_designation)),
+        created_at: None,
+        updated_at: None,
+    })?;
+
+    let today = Local::now().date_naive();
+    apply_due_promotions(&tx, today)?;
+    sync_current_salary(&tx, promotion.staff_id, today)?;
+
+    tx.commit()?;
+    Ok(id)
+}
+
+fn promotions(conn: &Connection, filter: &str, value: i32) -> Result<Vec<Promotion>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, staff_id, from_designation, from_designation_id, to_designation,
+         to_designation_id, from_salary_code, to_salary_code, basic_salary, effective_date,
+         approval_reference, remarks, created_at
+         FROM promotions WHERE {} = ?1 ORDER BY id",
+        filter
+    ))?;
+
+    let promotion_iter = stmt.query_map(params![value], |row| {
+        Ok(Promotion {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            from_designation: row.get(2)?,
+            from_designation_id: row.get(3)?,
+            to_designation: row.get(4)?,
+            to_designation_id: row.get(5)?,
+            from_salary_code: row.get(6)?,
+            to_salary_code: row.get(7)?,
+            basic_salary: row.get(8)?,
+            effective_date: row.get(9)?,
+            approval_reference: row.get(10)?,
+            remarks: row.get(11)?,
+            created_at: Some(row.get(12)?),
+        })
+    })?;
+
+    let mut promotions = Vec::new();
+    for promotion in promotion_iter {
+        promotions.push(promotion?);
+    }
+
+    Ok(promotions)
+}
+
+pub fn get_promotions_for_staff_from_db(staff_id: i32) -> Result<Vec<Promotion>> {
+    let conn = get_connection()?;
+    promotions(&conn, "staff_id", staff_id)
+}
+
+pub fn get_promotions_to_designation_from_db(designation_id: i32) -> Result<Vec<Promotion>> {
+    let conn = get_connection()?;
+    promotions(&conn, "to_designation_id", designation_id)
+}
\ No newline at end of file
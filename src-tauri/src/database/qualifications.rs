@@ -0,0 +1,236 @@
+use rusqlite::{params, Connection, Result};
+use chrono::Utc;
+use crate::database::models::{Qualification, TrainingCourse};
+use crate::database::operations::get_connection;
+
+pub const QUALIFICATION_CATEGORIES: [&str; 2] = ["academic", "professional"];
+
+pub fn create_qualification_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS qualifications (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            category TEXT NOT NULL,
+            title TEXT NOT NULL,
+            institution TEXT NOT NULL,
+            awarded_date TEXT,
+            result TEXT,
+            certificate_path TEXT,
+            remarks TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS training_courses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            course_name TEXT NOT NULL,
+            institution TEXT NOT NULL,
+            start_date TEXT NOT NULL,
+            end_date TEXT,
+            completed INTEGER NOT NULL DEFAULT 0,
+            result TEXT,
+            certificate_path TEXT,
+            remarks TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_qualifications_staff ON qualifications(staff_id)",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_training_courses_staff ON training_courses(staff_id)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+pub fn get_qualifications_from_db(staff_id: i32) -> Result<Vec<Qualification>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, category, title, institution, awarded_date, result,
+         certificate_path, remarks, created_at, updated_at
+         FROM qualifications WHERE staff_id = ?1 ORDER BY category, id"
+    )?;
+
+    let qualification_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(Qualification {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            category: row.get(2)?,
+            title: row.get(3)?,
+            institution: row.get(4)?,
+            awarded_date: row.get(5)?,
+            result: row.get(6)?,
+            certificate_path: row.get(7)?,
+            remarks: row.get(8)?,
+            created_at: Some(row.get(9)?),
+            updated_at: Some(row.get(10)?),
+        })
+    })?;
+
+    let mut qualifications = Vec::new();
+    for qualification in qualification_iter {
+        qualifications.push(qualification?);
+    }
+
+    Ok(qualifications)
+}
+
+pub fn insert_qualification_in_db(qualification: &Qualification) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO qualifications (
+            staff_id, category, title, institution, awarded_date, result,
+            certificate_path, remarks, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            qualification.staff_id,
+            qualification.category,
+            qualification.title,
+            qualification.institution,
+            qualification.awarded_date,
+            qualification.result,
+            qualification.certificate_path,
+            qualification.remarks,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn update_qualification_in_db(qualification: &Qualification) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE qualifications SET
+            category = ?1, title = ?2, institution = ?3, awarded_date = ?4, result = ?5,
+            certificate_path = ?6, remarks = ?7, updated_at = ?8
+         WHERE id = ?9",
+        params![
+            qualification.category,
+            qualification.title,
+            qualification.institution,
+            qualification.awarded_date,
+            qualification.result,
+            qualification.certificate_path,
+            qualification.remarks,
+            now,
+            qualification.id
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_qualification_from_db(id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM qualifications WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+pub fn get_training_courses_from_db(staff_id: i32) -> Result<Vec<TrainingCourse>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, course_name, institution, start_date, end_date, completed, result,
+         certificate_path, remarks, created_at, updated_at
+         FROM training_courses WHERE staff_id = ?1 ORDER BY id"
+    )?;
+
+    let course_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(TrainingCourse {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            course_name: row.get(2)?,
+            institution: row.get(3)?,
+            start_date: row.get(4)?,
+            end_date: row.get(5)?,
+            completed: row.get(6)?,
+            result: row.get(7)?,
+            certificate_path: row.get(8)?,
+            remarks: row.get(9)?,
+            created_at: Some(row.get(10)?),
+            updated_at: Some(row.get(11)?),
+        })
+    })?;
+
+    let mut courses = Vec::new();
+    for course in course_iter {
+        courses.push(course?);
+    }
+
+    Ok(courses)
+}
+
+pub fn insert_training_course_in_db(course: &TrainingCourse) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO training_courses (
+            staff_id, course_name, institution, start_date, end_date, completed, result,
+            certificate_path, remarks, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            course.staff_id,
+            course.course_name,
+            course.institution,
+            course.start_date,
+            course.end_date,
+            course.completed,
+            course.result,
+            course.certificate_path,
+            course.remarks,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn update_training_course_in_db(course: &TrainingCourse) -> Result<()> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE training_courses SET
+            course_name = ?1, institution = ?2, start_date = ?3, end_date = ?4, completed = ?5,
+            result = ?6, certificate_path = ?7, remarks = ?8, updated_at = ?9
+         WHERE id = ?10",
+        params![
+            course.course_name,
+            course.institution,
+            course.start_date,
+            course.end_date,
+            course.completed,
+            course.result,
+            course.certificate_path,
+            course.remarks,
+            now,
+            course.id
+        ],
+    )?;
+
+    Ok(())
+}
+
+pub fn delete_training_course_from_db(id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM training_courses WHERE id = ?1", params![id])?;
+    Ok(())
+}
\ No newline at end of file
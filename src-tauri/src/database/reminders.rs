@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use rusqlite::{params, Connection, Result};
+use chrono::{Datelike, Days, Months, NaiveDate, Utc};
+use crate::database::models::Reminder;
+use crate::database::operations::{get_connection, get_all_staff_from_db};
+use crate::database::reports::{build_retirement_forecast, retirement_date_of};
+use crate::database::increments::get_increments_due_from_db;
+use crate::database::probation::get_probation_due_from_db;
+use crate::utils::date_utils::{parse_date, format_date, day_month_in_year};
+
+/// How many days ahead each kind of reminder is raised
+pub const RETIREMENT_NOTICE_DAYS: u64 = 90;
+pub const INCREMENT_NOTICE_DAYS: u64 = 30;
+pub const PROBATION_NOTICE_DAYS: u64 = 30;
+pub const BIRTHDAY_NOTICE_DAYS: u64 = 7;
+pub const DOCUMENT_NOTICE_DAYS: u64 = 30;
+
+pub fn create_reminder_tables(conn: &Connection) -> Result<()> {
+    // Only reminders the user has acted on have a row; the reminders
+    // themselves are worked out afresh from staff records on every check
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS reminder_state (
+            reminder_key TEXT PRIMARY KEY,
+            status TEXT NOT NULL CHECK (status IN ('snoozed', 'dismissed')),
+            snoozed_until TEXT,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    Ok(())
+}
+
+fn reminder(key: String, category: &str, staff_id: i32, full_name: String, title: String, due: NaiveDate, today: NaiveDate) -> Reminder {
+    Reminder {
+        key,
+        category: category.to_string(),
+        staff_id,
+        full_name,
+        title,
+        due_date: format_date(due),
+        days_remaining: (due - today).num_days(),
+        status: "active".to_string(),
+        snoozed_until: None,
+    }
+}
+
+/// Document id, staff id, staff name, document title and expiry date
+type ExpiringDocument = (i32, i32, String, String, NaiveDate);
+
+fn document_expiries(conn: &Connection, until: NaiveDate) -> Result<Vec<ExpiringDocument>> {
+    let mut stmt = conn.prepare(
+        "SELECT d.id, d.staff_id, s.full_name, d.title, d.expiry_date
+         FROM staff_documents d JOIN staff s ON s.id = d.staff_id
+         WHERE d.expiry_date IS NOT NULL"
+    )?;
+
+    let document_iter = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, i32>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, String>(4)?,
+        ))
+    })?;
+
+    let mut expiring = Vec::new();
+    for document in document_iter {
+        let (id, staff_id, full_name, title, expiry_date) = document?;
+        if let Some(expiry) = parse_date(&expiry_date).filter(|expiry| *expiry <= until) {
+            expiring.push((id, staff_id, full_name, title, expiry));
+        }
+    }
+
+    Ok(expiring)
+}
+
+/// Every reminder falling due as of `today`: retirements, unapplied
+/// increments, probation ends, birthdays and expiring documents
+fn build_reminders(conn: &Connection, today: NaiveDate) -> Result<Vec<Reminder>> {
+    let ahead = |days: u64| today.checked_add_days(Days::new(days)).unwrap_or(today);
+    let mut reminders = Vec::new();
+
+    let forecast = build_retirement_forecast(today, ahead(RETIREMENT_NOTICE_DAYS), None)?;
+    for staff in forecast.groups.into_iter().flat_map(|group| group.staff) {
+        if let Some(date) = parse_date(&staff.date_of_retirement) {
+            reminders.push(reminder(
+                format!("retirement:{}:{}", staff.id, staff.date_of_retirement),
+                "retirement",
+                staff.id,
+                staff.full_name.clone(),
+                format!("{} retires on {}", staff.full_name, staff.date_of_retirement),
+                date,
+                today,
+            ));
+        }
+    }
+
+    // Increments already missed this month are kept until they are applied
+    let increment_until = ahead(INCREMENT_NOTICE_DAYS);
+    let mut month = today.with_day(1).unwrap_or(today);
+    while month <= increment_until {
+        for entry in get_increments_due_from_db(month.year(), month.month())? {
+            let effective = match parse_date(&entry.effective_date) {
+                Some(date) if !entry.already_applied && date <= increment_until => date,
+                _ => continue,
+            };
+
+            reminders.push(reminder(
+                format!("increment:{}:{}", entry.staff_id, entry.effective_date),
+                "increment",
+                entry.staff_id,
+                entry.full_name.clone(),
+                format!("Increment of {:.2} due to {} on {}", entry.increment_amount, entry.full_name, entry.effective_date),
+                effective,
+                today,
+            ));
+        }
+
+        month = match month.checked_add_months(Months::new(1)) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+
+    for entry in get_probation_due_from_db(today, ahead(PROBATION_NOTICE_DAYS))? {
+        if let Some(end) = parse_date(&entry.probation_end_date) {
+            let title = if entry.lapsed {
+                format!("Probation of {} ended on {} without confirmation", entry.full_name, entry.probation_end_date)
+            } else {
+                format!("Probation of {} ends on {}", entry.full_name, entry.probation_end_date)
+            };
+
+            reminders.push(reminder(
+                format!("probation:{}:{}", entry.staff_id, entry.probation_end_date),
+                "probation",
+                entry.staff_id,
+                entry.full_name,
+                title,
+                end,
+                today,
+            ));
+        }
+    }
+
+    let birthday_until = ahead(BIRTHDAY_NOTICE_DAYS);
+    for staff in get_all_staff_from_db()? {
+        let staff_id = staff.id.unwrap_or_default();
+
+        // Retired staff are kept on record but no longer celebrated at the office
+        if retirement_date_of(&staff.date_of_retirement, &staff.date_of_birth).is_some_and(|retired| retired < today) {
+            continue;
+        }
+
+        let dob = match parse_date(&staff.date_of_birth) {
+            Some(date) => date,
+            None => continue,
+        };
+        let day_month = format!("{:02}-{:02}", dob.day(), dob.month());
+        let birthday = match day_month_in_year(&day_month, today.year()) {
+            Some(date) if date >= today => Some(date),
+            _ => day_month_in_year(&day_month, today.year() + 1),
+        };
+
+        if let Some(birthday) = birthday.filter(|date| *date <= birthday_until) {
+            reminders.push(reminder(
+                format!("birthday:{}:{}", staff_id, format_date(birthday)),
+                "birthday",
+                staff_id,
+                staff.full_name.clone(),
+                format!("{} turns {} on {}", staff.full_name, birthday.year() - dob.year(), format_date(birthday)),
+                birthday,
+                today,
+            ));
+        }
+    }
+
+    for (id, staff_id, full_name, title, expiry) in document_expiries(conn, ahead(DOCUMENT_NOTICE_DAYS))? {
+        let verb = if expiry < today { "expired" } else { "expires" };
+        reminders.push(reminder(
+            format!("document:{}:{}", id, format_date(expiry)),
+            "document",
+            staff_id,
+            full_name.clone(),
+            format!("'{}' of {} {} on {}", title, full_name, verb, format_date(expiry)),
+            expiry,
+            today,
+        ));
+    }
+
+    reminders.sort_by(|a, b| a.days_remaining.cmp(&b.days_remaining).then_with(|| a.full_name.cmp(&b.full_name)));
+    Ok(reminders)
+}
+
+/// Reminders due as of `today` with their snoozed or dismissed status. A
+/// snooze lapses on the day it runs out. Hidden reminders are left out
+/// unless `include_hidden` is set.
+pub fn get_reminders_from_db(today: NaiveDate, include_hidden: bool) -> Result<Vec<Reminder>> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn.prepare("SELECT reminder_key, status, snoozed_until FROM reminder_state")?;
+    let state_iter = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, (row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?)))
+    })?;
+
+    let mut states = HashMap::new();
+    for state in state_iter {
+        let (key, state) = state?;
+        states.insert(key, state);
+    }
+
+    let mut reminders = Vec::new();
+    for mut reminder in build_reminders(&conn, today)? {
+        match states.remove(&reminder.key) {
+            Some((status, _)) if status == "dismissed" => reminder.status = status,
+            Some((status, Some(until))) if parse_date(&until).is_some_and(|until| until > today) => {
+                reminder.status = status;
+                reminder.snoozed_until = Some(until);
+            }
+            _ => {}
+        }
+
+        if include_hidden || reminder.status == "active" {
+            reminders.push(reminder);
+        }
+    }
+
+    Ok(reminders)
+}
+
+/// Records that a reminder was snoozed until `until` (dd-MM-yyyy) or, with
+/// no date, dismissed
+pub fn set_reminder_state_in_db(key: &str, until: Option<&str>) -> Result<usize> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let status = if until.is_some() { "snoozed" } else { "dismissed" };
+
+    conn.execute(
+        "INSERT INTO reminder_state (reminder_key, status, snoozed_until, updated_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(reminder_key) DO UPDATE SET
+            status = excluded.status,
+            snoozed_until = excluded.snoozed_until,
+            updated_at = excluded.updated_at",
+        params![key, status, until, now],
+    )
+}
+
+pub fn clear_reminder_state_in_db(key: &str) -> Result<usize> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM reminder_state WHERE reminder_key = ?1", params![key])
+}
+
+/// Reminder kinds whose keys name the staff member, as `kind:staff_id:date`
+const STAFF_REMINDER_KINDS: [&str; 4] = ["retirement", "increment", "probation", "birthday"];
+
+/// Moves snoozed and dismissed reminders from one staff record to another
+/// when the records are merged. Where both already have a state for the
+/// same reminder, the kept record's state wins.
+pub fn move_reminder_state(conn: &Connection, from_staff_id: i32, to_staff_id: i32) -> Result<()> {
+    for kind in STAFF_REMINDER_KINDS {
+        let from_prefix = format!("{}:{}:", kind, from_staff_id);
+        let to_prefix = format!("{}:{}:", kind, to_staff_id);
+
+        let mut stmt = conn.prepare("SELECT reminder_key FROM reminder_state WHERE substr(reminder_key, 1, ?2) = ?1")?;
+        let keys = stmt.query_map(params![from_prefix, from_prefix.len() as i64], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>>>()?;
+
+        for key in keys {
+            let moved = format!("{}{}", to_prefix, &key[from_prefix.len()..]);
+            conn.execute("UPDATE OR IGNORE reminder_state SET reminder_key = ?1 WHERE reminder_key = ?2", params![moved, key])?;
+            conn.execute("DELETE FROM reminder_state WHERE reminder_key = ?1", params![key])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Forgets snoozed and dismissed reminders for a document that is removed
+pub fn clear_document_reminder_state(conn: &Connection, document_id: i32) -> Result<usize> {
+    let prefix = format!("document:{}:", document_id);
+    conn.execute(
+        "DELETE FROM reminder_state WHERE substr(reminder_key, 1, ?2) = ?1",
+        params![prefix, prefix.len() as i64],
+    )
+}
\ No newline at end of file
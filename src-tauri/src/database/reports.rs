@@ -0,0 +1,73 @@
+use rusqlite::Result;
+use chrono::{Datelike, NaiveDate};
+use crate::database::models::{RetirementForecast, RetirementGroup, RetiringStaff};
+use crate::database::operations::get_all_staff_from_db;
+use crate::utils::date_utils::{parse_date, format_date, add_years};
+
+pub const RETIREMENT_AGE: i32 = 60;
+
+/// Retirement date of a staff member, taken from `date_of_retirement` when it
+/// holds a valid date and otherwise derived from the date of birth
+pub fn retirement_date_of(date_of_retirement: &str, date_of_birth: &str) -> Option<NaiveDate> {
+    parse_date(date_of_retirement)
+        .or_else(|| parse_date(date_of_birth).and_then(|dob| add_years(dob, RETIREMENT_AGE)))
+}
+
+/// Staff retiring between the two dates, from one office or from every
+/// office when `office_id` is None
+pub fn build_retirement_forecast(from: NaiveDate, to: NaiveDate, office_id: Option<i32>) -> Result<RetirementForecast> {
+    let mut retiring: Vec<(NaiveDate, RetiringStaff)> = Vec::new();
+
+    for staff in get_all_staff_from_db()? {
+        if office_id.is_some() && staff.office_id != office_id {
+            continue;
+        }
+
+        let retirement = match retirement_date_of(&staff.date_of_retirement, &staff.date_of_birth) {
+            Some(date) if date >= from && date <= to => date,
+            _ => continue,
+        };
+
+        retiring.push((retirement, RetiringStaff {
+            id: staff.id.unwrap_or_default(),
+            appointment_number: staff.appointment_number,
+            full_name: staff.full_name,
+            designation: staff.designation,
+            salary_code: staff.salary_code,
+            date_of_retirement: format_date(retirement),
+            basic_salary: staff.basic_salary,
+        }));
+    }
+
+    retiring.sort_by(|(a_date, a), (b_date, b)| {
+        a_date.year().cmp(&b_date.year())
+            .then_with(|| a.designation.cmp(&b.designation))
+            .then_with(|| a_date.cmp(b_date))
+    });
+
+    let mut groups: Vec<RetirementGroup> = Vec::new();
+    for (date, staff) in retiring {
+        match groups.last_mut() {
+            Some(group) if group.year == date.year() && group.designation == staff.designation => {
+                group.count += 1;
+                group.salary_bill += staff.basic_salary;
+                group.staff.push(staff);
+            }
+            _ => groups.push(RetirementGroup {
+                year: date.year(),
+                designation: staff.designation.clone(),
+                count: 1,
+                salary_bill: staff.basic_salary,
+                staff: vec![staff],
+            }),
+        }
+    }
+
+    Ok(RetirementForecast {
+        from_date: format_date(from),
+        to_date: format_date(to),
+        total_retiring: groups.iter().map(|g| g.count).sum(),
+        total_salary_bill: groups.iter().map(|g| g.salary_bill).sum(),
+        groups,
+    })
+}
\ No newline at end of file
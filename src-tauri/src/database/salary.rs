@@ -0,0 +1,321 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{Local, NaiveDate, Utc};
+use crate::database::models::{SalaryHistoryEntry, SalaryScale, SalaryScaleSegment};
+use crate::database::operations::get_connection;
+use crate::utils::date_utils::parse_date;
+
+pub const SALARY_CHANGE_REASONS: [&str; 5] = ["appointment", "increment", "promotion", "revision", "correction"];
+
+pub fn create_salary_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS salary_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            effective_date TEXT NOT NULL,
+            salary_code TEXT NOT NULL,
+            basic_salary REAL NOT NULL,
+            increment_amount REAL NOT NULL,
+            reason TEXT NOT NULL,
+            reference_number TEXT,
+            remarks TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_salary_history_staff ON salary_history(staff_id)",
+        [],
+    )?;
+
+    // Salary scales are versioned by the date a circular brings them into force
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS salary_scales (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            salary_code TEXT NOT NULL,
+            initial_salary REAL NOT NULL,
+            maximum_salary REAL NOT NULL,
+            circular_reference TEXT,
+            effective_from TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            UNIQUE(salary_code, effective_from)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS salary_scale_segments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            scale_id INTEGER NOT NULL REFERENCES salary_scales(id) ON DELETE CASCADE,
+            segment_order INTEGER NOT NULL,
+            step_count INTEGER NOT NULL,
+            increment_amount REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Inserts a history row on an existing connection so callers can include it
+/// in their own transaction
+pub fn insert_salary_history(conn: &Connection, entry: &SalaryHistoryEntry) -> Result<i64> {
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO salary_history (
+            staff_id, effective_date, salary_code, basic_salary, increment_amount,
+            reason, reference_number, remarks, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            entry.staff_id,
+            entry.effective_date,
+            entry.salary_code,
+            entry.basic_salary,
+            entry.increment_amount,
+            entry.reason,
+            entry.reference_number,
+            entry.remarks,
+            now,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+fn salary_history(conn: &Connection, staff_id: i32) -> Result<Vec<SalaryHistoryEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, effective_date, salary_code, basic_salary, increment_amount,
+         reason, reference_number, remarks, created_at, updated_at
+         FROM salary_history WHERE staff_id = ?1 ORDER BY id"
+    )?;
+
+    let entry_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(SalaryHistoryEntry {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            effective_date: row.get(2)?,
+            salary_code: row.get(3)?,
+            basic_salary: row.get(4)?,
+            increment_amount: row.get(5)?,
+            reason: row.get(6)?,
+            reference_number: row.get(7)?,
+            remarks: row.get(8)?,
+            created_at: Some(row.get(9)?),
+            updated_at: Some(row.get(10)?),
+        })
+    })?;
+
+    let mut entries = Vec::new();
+    for entry in entry_iter {
+        entries.push(entry?);
+    }
+
+    // Dates are stored as dd-MM-yyyy, so order chronologically here rather than in SQL.
+    // Entries sharing a date keep the order in which they were recorded.
+    entries.sort_by_key(|entry| parse_date(&entry.effective_date));
+
+    Ok(entries)
+}
+
+/// Copies the latest history entry already in effect on `today` onto the
+/// staff row. Entries recorded in advance wait until their effective date;
+/// until then the current salary is left as it is.
+///
+/// This is the only place the salary columns of an existing staff row are
+/// written: every salary change is recorded as a history entry and then
+/// applied from here.
+pub fn sync_current_salary(conn: &Connection, staff_id: i32, today: NaiveDate) -> Result<()> {
+    let history = salary_history(conn, staff_id)?;
+    let current = history.iter()
+        .rev()
+        .find(|entry| parse_date(&entry.effective_date).is_some_and(|date| date <= today));
+
+    if let Some(current) = current {
+        let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        conn.execute(
+            "UPDATE staff SET salary_code = ?1, basic_salary = ?2, increment_amount = ?3, updated_at = ?4
+             WHERE id = ?5 AND (salary_code != ?1 OR basic_salary != ?2 OR increment_amount != ?3)",
+            params![current.salary_code, current.basic_salary, current.increment_amount, now, staff_id],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Brings every staff row up to date with salary changes that have come
+/// into effect since the last check
+pub fn sync_current_salaries(conn: &Connection, today: NaiveDate) -> Result<()> {
+    let mut stmt = conn.prepare("SELECT id FROM staff")?;
+    let staff_ids = stmt.query_map([], |row| row.get::<_, i32>(0))?.collect::<Result<Vec<_>>>()?;
+
+    for staff_id in staff_ids {
+        sync_current_salary(conn, staff_id, today)?;
+    }
+
+    Ok(())
+}
+
+pub fn sync_current_salaries_in_db(today: NaiveDate) -> Result<()> {
+    let conn = get_connection()?;
+    sync_current_salaries(&conn, today)
+}
+
+pub fn record_salary_change_in_db(entry: &SalaryHistoryEntry) -> Result<i64> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+
+    let id = insert_salary_history(&tx, entry)?;
+    sync_current_salary(&tx, entry.staff_id, Local::now().date_naive())?;
+
+    tx.commit()?;
+    Ok(id)
+}
+
+pub fn get_salary_history_from_db(staff_id: i32) -> Result<Vec<SalaryHistoryEntry>> {
+    let conn = get_connection()?;
+    salary_history(&conn, staff_id)
+}
+
+pub fn correct_salary_entry_in_db(entry: &SalaryHistoryEntry) -> Result<()> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let updated = tx.execute(
+        "UPDATE salary_history SET
+            effective_date = ?1, salary_code = ?2, basic_salary = ?3, increment_amount = ?4,
+            reason = ?5, reference_number = ?6, remarks = ?7, updated_at = ?8
+         WHERE id = ?9 AND staff_id = ?10",
+        params![
+            entry.effective_date,
+            entry.salary_code,
+            entry.basic_salary,
+            entry.increment_amount,
+            entry.reason,
+            entry.reference_number,
+            entry.remarks,
+            now,
+            entry.id,
+            entry.staff_id
+        ],
+    )?;
+    if updated == 0 {
+        return Err(rusqlite::Error::QueryReturnedNoRows);
+    }
+    sync_current_salary(&tx, entry.staff_id, Local::now().date_naive())?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+/// Saves a set of scales, typically all those issued by one circular, in a
+/// single transaction. A scale with the same code and effective date replaces
+/// the existing one.
+pub fn save_salary_scales_in_db(scales: &[SalaryScale]) -> Result<usize> {
+    let mut conn = get_connection()?;
+    let tx = conn.transaction()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    for scale in scales {
+        tx.execute(
+            "DELETE FROM salary_scales WHERE salary_code = ?1 AND effective_from = ?2",
+            params![scale.salary_code, scale.effective_from],
+        )?;
+
+        tx.execute(
+            "INSERT INTO salary_scales (
+                salary_code, initial_salary, maximum_salary, circular_reference,
+                effective_from, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                scale.salary_code,
+                scale.initial_salary,
+                scale.maximum_salary,
+                scale.circular_reference,
+                scale.effective_from,
+                now
+            ],
+        )?;
+        let scale_id = tx.last_insert_rowid();
+
+        for (order, segment) in scale.segments.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO salary_scale_segments (scale_id, segment_order, step_count, increment_amount)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![scale_id, order as i32, segment.step_count, segment.increment_amount],
+            )?;
+        }
+    }
+
+    tx.commit()?;
+    Ok(scales.len())
+}
+
+fn salary_scales(conn: &Connection) -> Result<Vec<SalaryScale>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, salary_code, initial_salary, maximum_salary, circular_reference,
+         effective_from, created_at
+         FROM salary_scales ORDER BY salary_code, id"
+    )?;
+
+    let scale_iter = stmt.query_map([], |row| {
+        Ok(SalaryScale {
+            id: Some(row.get(0)?),
+            salary_code: row.get(1)?,
+            initial_salary: row.get(2)?,
+            maximum_salary: row.get(3)?,
+            segments: Vec::new(),
+            circular_reference: row.get(4)?,
+            effective_from: row.get(5)?,
+            created_at: Some(row.get(6)?),
+        })
+    })?;
+
+    let mut segment_stmt = conn.prepare(
+        "SELECT step_count, increment_amount FROM salary_scale_segments
+         WHERE scale_id = ?1 ORDER BY segment_order"
+    )?;
+
+    let mut scales = Vec::new();
+    for scale in scale_iter {
+        let mut scale = scale?;
+        let segment_iter = segment_stmt.query_map(params![scale.id], |row| {
+            Ok(SalaryScaleSegment {
+                step_count: row.get(0)?,
+                increment_amount: row.get(1)?,
+            })
+        })?;
+        for segment in segment_iter {
+            scale.segments.push(segment?);
+        }
+        scales.push(scale);
+    }
+
+    Ok(scales)
+}
+
+pub fn get_salary_scales_from_db() -> Result<Vec<SalaryScale>> {
+    let conn = get_connection()?;
+    salary_scales(&conn)
+}
+
+/// The scale in force today for a salary code, if one has been loaded
+pub fn current_salary_scale(conn: &Connection, salary_code: &str) -> Result<Option<SalaryScale>> {
+    let today = Local::now().date_naive();
+
+    Ok(salary_scales(conn)?
+        .into_iter()
+        .filter(|scale| scale.salary_code == salary_code)
+        .filter_map(|scale| parse_date(&scale.effective_from).map(|from| (from, scale)))
+        .filter(|(from, _)| *from <= today)
+        .max_by_key(|(from, _)| *from)
+        .map(|(_, scale)| scale))
+}
+
+pub fn get_current_salary_scale_from_db(salary_code: &str) -> Result<Option<SalaryScale>> {
+    let conn = get_connection()?;
+    current_salary_scale(&conn, salary_code)
+}
\ No newline at end of file
@@ -0,0 +1,256 @@
+use rusqlite::{params, Connection, Result};
+use chrono::{Days, NaiveDate, Utc};
+use crate::database::models::{ServiceBreak, ServiceLength, ServiceMilestone};
+use crate::database::operations::get_connection;
+use crate::database::reports::retirement_date_of;
+use crate::utils::date_utils::{parse_date, format_date, add_years, days_covered, years_months_days_between};
+
+pub const SERVICE_MILESTONES: [i32; 3] = [10, 20, 25];
+
+/// Periods of time, first and last day inclusive
+pub type Periods = Vec<(NaiveDate, NaiveDate)>;
+
+pub fn create_service_tables(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS service_breaks (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            staff_id INTEGER NOT NULL REFERENCES staff(id) ON DELETE CASCADE,
+            from_date TEXT NOT NULL,
+            to_date TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            reference_number TEXT,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_service_breaks_staff ON service_breaks(staff_id)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+fn service_breaks(conn: &Connection, staff_id: i32) -> Result<Vec<ServiceBreak>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, staff_id, from_date, to_date, reason, reference_number, created_at
+         FROM service_breaks WHERE staff_id = ?1 ORDER BY id"
+    )?;
+
+    let break_iter = stmt.query_map(params![staff_id], |row| {
+        Ok(ServiceBreak {
+            id: Some(row.get(0)?),
+            staff_id: row.get(1)?,
+            from_date: row.get(2)?,
+            to_date: row.get(3)?,
+            reason: row.get(4)?,
+            reference_number: row.get(5)?,
+            created_at: Some(row.get(6)?),
+        })
+    })?;
+
+    let mut breaks = Vec::new();
+    for service_break in break_iter {
+        breaks.push(service_break?);
+    }
+
+    Ok(breaks)
+}
+
+pub fn get_service_breaks_from_db(staff_id: i32) -> Result<Vec<ServiceBreak>> {
+    let conn = get_connection()?;
+    service_breaks(&conn, staff_id)
+}
+
+pub fn insert_service_break_in_db(service_break: &ServiceBreak) -> Result<i64> {
+    let conn = get_connection()?;
+    let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO service_breaks (staff_id, from_date, to_date, reason, reference_number, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            service_break.staff_id,
+            service_break.from_date,
+            service_break.to_date,
+            service_break.reason,
+            service_break.reference_number,
+            now
+        ],
+    )?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+pub fn delete_service_break_from_db(id: i32) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute("DELETE FROM service_breaks WHERE id = ?1", params![id])?;
+    Ok(())
+}
+
+/// Recorded periods that do not count as service: breaks in service and
+/// approved no-pay leave, returned separately
+pub fn service_deductions(conn: &Connection, staff_id: i32) -> Result<(Periods, Periods)> {
+    let breaks = service_breaks(conn, staff_id)?
+        .iter()
+        .filter_map(|b| Some((parse_date(&b.from_date)?, parse_date(&b.to_date)?)))
+        .collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT a.from_date, a.to_date FROM leave_applications a
+         JOIN leave_types t ON t.id = a.leave_type_id
+         WHERE a.staff_id = ?1 AND a.status = 'approved' AND t.code = 'no_pay'"
+    )?;
+    let period_iter = stmt.query_map(params![staff_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+
+    let mut no_pay = Vec::new();
+    for period in period_iter {
+        let (from, to) = period?;
+        if let (Some(from), Some(to)) = (parse_date(&from), parse_date(&to)) {
+            no_pay.push((from, to));
+        }
+    }
+
+    Ok((breaks, no_pay))
+}
+
+/// Date on which net service reaches `years`, pushing the anniversary back by
+/// every deducted day that falls before it
+fn date_reaching(appointed: NaiveDate, years: i32, deductions: &[(NaiveDate, NaiveDate)]) -> Option<NaiveDate> {
+    let anniversary = add_years(appointed, years)?;
+    let mut reached = anniversary;
+
+    loop {
+        let deducted = days_covered(deductions, appointed, reached) as u64;
+        let next = anniversary.checked_add_days(Days::new(deducted))?;
+        if next == reached {
+            return Some(reached);
+        }
+        reached = next;
+    }
+}
+
+/// Net service of a staff member up to `as_of`, or up to retirement if that
+/// comes first. None if the staff member or their appointment date is missing.
+pub fn get_service_length_from_db(staff_id: i32, as_of: NaiveDate) -> Result<Option<ServiceLength>> {
+    let conn = get_connection()?;
+
+    let dates = conn.query_row(
+        "SELECT date_of_first_appointment, date_of_retirement, date_of_birth FROM staff WHERE id = ?1",
+        params![staff_id],
+        |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?)),
+    );
+
+    let (first_appointment, date_of_retirement, date_of_birth) = match dates {
+        Ok(dates) => dates,
+        Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let appointed = match parse_date(&first_appointment) {
+        Some(date) => date,
+        None => return Ok(None),
+    };
+
+    let end = match retirement_date_of(&date_of_retirement, &date_of_birth) {
+        Some(retirement) if retirement < as_of => retirement,
+        _ => as_of,
+    }
+    .max(appointed);
+
+    let (breaks, no_pay) = service_deductions(&conn, staff_id)?;
+    let all: Periods = breaks.iter().chain(no_pay.iter()).copied().collect();
+    let deducted_days = days_covered(&all, appointed, end);
+
+    let net_end = end.checked_sub_days(Days::new(deducted_days as u64)).unwrap_or(appointed).max(appointed);
+    let (years, months, days) = years_months_days_between(appointed, net_end);
+
+    Ok(Some(ServiceLength {
+        staff_id,
+        date_of_first_appointment: format_date(appointed),
+        as_of: format_date(end),
+        years,
+        months,
+        days,
+        gross_days: (end - appointed).num_days(),
+        break_days: days_covered(&breaks, appointed, end),
+        no_pay_days: days_covered(&no_pay, appointed, end),
+        deducted_days,
+    }))
+}
+
+/// Staff whose net service reaches 10, 20 or 25 years, or the minimum
+/// pensionable service, between the two dates (inclusive)
+pub fn get_service_milestones_from_db(from: NaiveDate, to: NaiveDate, minimum_pensionable_years: i32) -> Result<Vec<ServiceMilestone>> {
+    let conn = get_connection()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, appointment_number, full_name, designation, date_of_first_appointment,
+         date_of_retirement, date_of_birth
+         FROM staff ORDER BY full_name"
+    )?;
+
+    let staff_iter = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, String>(4)?,
+            row.get::<_, String>(5)?,
+            row.get::<_, String>(6)?,
+        ))
+    })?;
+
+    let mut milestones = Vec::new();
+    for staff in staff_iter {
+        let (staff_id, appointment_number, full_name, designation, first_appointment, date_of_retirement, date_of_birth) = staff?;
+
+        let appointed = match parse_date(&first_appointment) {
+            Some(date) => date,
+            None => continue,
+        };
+        let retirement = retirement_date_of(&date_of_retirement, &date_of_birth);
+
+        let (breaks, no_pay) = service_deductions(&conn, staff_id)?;
+        let deductions: Periods = breaks.into_iter().chain(no_pay).collect();
+
+        let mut targets: Vec<(i32, String)> = SERVICE_MILESTONES.iter()
+            .map(|years| (*years, format!("{} years of service", years)))
+            .collect();
+        match targets.iter_mut().find(|(years, _)| *years == minimum_pensionable_years) {
+            Some((_, label)) => label.push_str(" (minimum pensionable service)"),
+            None => targets.push((
+                minimum_pensionable_years,
+                format!("Minimum pensionable service ({} years)", minimum_pensionable_years),
+            )),
+        }
+
+        for (years, milestone) in targets {
+            let reached = match date_reaching(appointed, years, &deductions) {
+                Some(date) if date >= from && date <= to => date,
+                _ => continue,
+            };
+
+            // Milestones after retirement are never reached
+            if retirement.is_some_and(|retirement| reached > retirement) {
+                continue;
+            }
+
+            milestones.push((reached, ServiceMilestone {
+                staff_id,
+                appointment_number: appointment_number.clone(),
+                full_name: full_name.clone(),
+                designation: designation.clone(),
+                years,
+                milestone,
+                milestone_date: format_date(reached),
+            }));
+        }
+    }
+
+    milestones.sort_by(|(a_date, a), (b_date, b)| a_date.cmp(b_date).then_with(|| a.full_name.cmp(&b.full_name)));
+    Ok(milestones.into_iter().map(|(_, milestone)| milestone).collect())
+}
\ No newline at end of file
@@ -8,12 +8,72 @@ mod utils;
 use database::operations::initialize_database;
 use commands::staff::{
     add_staff, get_all_staff, get_staff_by_id, update_staff, delete_staff,
-    search_staff, get_staff_count, export_staff_to_pdf
+    search_staff, get_staff_count, get_workforce_stats, export_staff_to_pdf
 };
+use commands::reports::{get_retirement_forecast, export_retirement_forecast, export_personal_file};
+use commands::increments::{
+    get_increments_due, preview_increments, apply_increments, get_increment_history
+};
+use commands::salary::{
+    record_salary_change, get_salary_history, correct_salary_entry,
+    import_salary_scales, get_salary_scales, get_salary_step
+};
+use commands::designations::{
+    get_designations, add_designation, update_designation, delete_designation,
+    get_vacancy_report, set_office_cadre
+};
+use commands::postings::{
+    get_staff_postings, add_posting, update_posting, delete_posting,
+    record_transfer, get_staff_at_station
+};
+use commands::promotions::{promote_staff, get_promotion_history, get_seniority_list};
+use commands::leave::{
+    get_leave_types, update_leave_type, apply_leave, approve_leave,
+    reject_leave, get_leave_applications, get_leave_balances,
+    carry_forward_leave, get_leave_register, set_leave_entitlement
+};
+use commands::attendance::{
+    import_attendance, get_attendance_mappings, set_attendance_mapping,
+    delete_attendance_mapping, get_office_hours, update_office_hours,
+    get_daily_attendance, get_attendance_summary, get_holidays, add_holiday,
+    delete_holiday
+};
+use commands::dependents::{get_dependents, add_dependent, update_dependent, delete_dependent};
+use commands::qualifications::{
+    get_qualifications, add_qualification, update_qualification,
+    delete_qualification, get_training_courses, add_training_course,
+    update_training_course, delete_training_course
+};
+use commands::documents::{
+    attach_document, get_staff_documents, open_document, export_document,
+    delete_document, verify_documents
+};
+use commands::notes::{set_notes_passphrase, add_staff_note, get_staff_notes};
+use commands::pension::{
+    get_pension_rules, update_pension_rules, get_pension_estimate,
+    export_pension_statement
+};
+use commands::service::{
+    get_service_length, get_service_breaks, add_service_break,
+    delete_service_break, get_service_milestones
+};
+use commands::probation::{get_probation_due, confirm_probation, clear_probation};
+use commands::reminders::{
+    get_reminders, snooze_reminder, dismiss_reminder, restore_reminder,
+    reminder_tray, handle_tray_event, start_reminder_engine
+};
+use commands::offices::{get_offices, add_office, update_office, delete_office, set_default_office};
+use commands::nic::{
+    get_nic_details, get_nic_mismatch_report, convert_nic,
+    normalize_nic_numbers, convert_nic_to_legacy
+};
+use commands::duplicates::{find_duplicate_staff, merge_staff, get_staff_merges};
 
 fn main() {
     tauri::Builder::default()
-        .setup(|_app| {
+        .system_tray(reminder_tray())
+        .on_system_tray_event(|app, event| handle_tray_event(app, event))
+        .setup(|app| {
             // Initialize database on startup
             match initialize_database() {
                 Ok(_) => println!("Database initialized successfully"),
@@ -22,6 +82,9 @@ fn main() {
                     std::process::exit(1);
                 }
             }
+
+            // Check reminders now and again each day
+            start_reminder_engine(app.handle());
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -32,7 +95,107 @@ fn main() {
             delete_staff,
             search_staff,
             get_staff_count,
-            export_staff_to_pdf
+            get_workforce_stats,
+            export_staff_to_pdf,
+            get_retirement_forecast,
+            export_retirement_forecast,
+            export_personal_file,
+            get_increments_due,
+            preview_increments,
+            apply_increments,
+            get_increment_history,
+            record_salary_change,
+            get_salary_history,
+            correct_salary_entry,
+            import_salary_scales,
+            get_salary_scales,
+            get_salary_step,
+            get_designations,
+            add_designation,
+            update_designation,
+            delete_designation,
+            get_vacancy_report,
+            get_staff_postings,
+            add_posting,
+            update_posting,
+            delete_posting,
+            record_transfer,
+            get_staff_at_station,
+            promote_staff,
+            get_promotion_history,
+            get_seniority_list,
+            get_leave_types,
+            update_leave_type,
+            apply_leave,
+            approve_leave,
+            reject_leave,
+            get_leave_applications,
+            get_leave_balances,
+            carry_forward_leave,
+            get_leave_register,
+            import_attendance,
+            get_attendance_mappings,
+            set_attendance_mapping,
+            delete_attendance_mapping,
+            get_office_hours,
+            update_office_hours,
+            get_daily_attendance,
+            get_attendance_summary,
+            get_dependents,
+            add_dependent,
+            update_dependent,
+            delete_dependent,
+            get_qualifications,
+            add_qualification,
+            update_qualification,
+            delete_qualification,
+            get_training_courses,
+            add_training_course,
+            update_training_course,
+            delete_training_course,
+            attach_document,
+            get_staff_documents,
+            open_document,
+            export_document,
+            delete_document,
+            verify_documents,
+            set_notes_passphrase,
+            add_staff_note,
+            get_staff_notes,
+            get_pension_rules,
+            update_pension_rules,
+            get_pension_estimate,
+            export_pension_statement,
+            get_service_length,
+            get_service_breaks,
+            add_service_break,
+            delete_service_break,
+            get_service_milestones,
+            get_probation_due,
+            confirm_probation,
+            get_reminders,
+            snooze_reminder,
+            dismiss_reminder,
+            restore_reminder,
+            get_offices,
+            add_office,
+            update_office,
+            delete_office,
+            set_default_office,
+            get_nic_details,
+            get_nic_mismatch_report,
+            convert_nic,
+            normalize_nic_numbers,
+            find_duplicate_staff,
+            merge_staff,
+            get_staff_merges,
+            convert_nic_to_legacy,
+            clear_probation,
+            get_holidays,
+            add_holiday,
+            delete_holiday,
+            set_leave_entitlement,
+            set_office_cadre
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
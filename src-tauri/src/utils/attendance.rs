@@ -0,0 +1,123 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Format punch times are stored in, so they sort chronologically as text
+pub const PUNCH_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+const DATE_FORMATS: [&str; 4] = ["%Y-%m-%d", "%d-%m-%Y", "%d/%m/%Y", "%Y/%m/%d"];
+const TIME_FORMATS: [&str; 2] = ["%H:%M:%S", "%H:%M"];
+
+/// A single fingerprint punch read from a device export
+#[derive(Debug, PartialEq)]
+pub struct Punch {
+    pub device_user_id: String,
+    pub time: NaiveDateTime,
+}
+
+/// Punches read from an export, with the line numbers that could not be read
+#[derive(Debug, Default)]
+pub struct ParsedPunchLog {
+    pub punches: Vec<Punch>,
+    pub invalid_lines: Vec<usize>,
+}
+
+/// Reads a punch log exported by the fingerprint terminal. Lines may be comma
+/// or tab/space separated; the first field is the device user ID, followed by
+/// the date and time either as one field or two. Columns after the time (verify
+/// mode, in/out state, work code) are ignored. A header line is skipped.
+pub fn parse_punch_log(content: &str) -> ParsedPunchLog {
+    let mut log = ParsedPunchLog::default();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim().trim_start_matches('\u{feff}');
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_punch_line(line) {
+            Some(punch) => log.punches.push(punch),
+            // Only the first line may be a header
+            None if index == 0 && !line.chars().next().is_some_and(|c| c.is_ascii_digit()) => {}
+            None => log.invalid_lines.push(index + 1),
+        }
+    }
+
+    log
+}
+
+fn parse_punch_line(line: &str) -> Option<Punch> {
+    let fields: Vec<&str> = if line.contains(',') {
+        line.split(',').map(|field| field.trim().trim_matches('"')).collect()
+    } else {
+        line.split_whitespace().collect()
+    };
+
+    let device_user_id = fields.first()?.trim_start_matches('0');
+    let device_user_id = if device_user_id.is_empty() { "0" } else { device_user_id };
+    if !device_user_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let time = match fields.get(1)?.split_once(' ') {
+        Some((date, time)) => parse_date_time(date, time)?,
+        None => parse_date_time(fields.get(1)?, fields.get(2)?)?,
+    };
+
+    Some(Punch {
+        device_user_id: device_user_id.to_string(),
+        time,
+    })
+}
+
+fn parse_date_time(date: &str, time: &str) -> Option<NaiveDateTime> {
+    let date = DATE_FORMATS.iter().find_map(|format| NaiveDate::parse_from_str(date.trim(), format).ok())?;
+    let time = parse_time(time)?;
+    Some(date.and_time(time))
+}
+
+/// Parses an HH:MM or HH:MM:SS time
+pub fn parse_time(time: &str) -> Option<NaiveTime> {
+    TIME_FORMATS.iter().find_map(|format| NaiveTime::parse_from_str(time.trim(), format).ok())
+}
+
+/// Minutes after the grace period that a check-in counts as late, or 0
+pub fn late_minutes(check_in: NaiveTime, office_start: NaiveTime, grace_minutes: i64) -> i64 {
+    let minutes = (check_in - office_start).num_minutes();
+    if minutes > grace_minutes { minutes } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: (i32, u32, u32), time: (u32, u32, u32)) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap()
+            .and_hms_opt(time.0, time.1, time.2).unwrap()
+    }
+
+    #[test]
+    fn test_parse_punch_log_text_export() {
+        let log = parse_punch_log("     1\t2024-01-08 08:12:33\t1\t0\t1\t0\n  0012\t2024-01-08 16:20:01\t1\t1\t1\t0\n");
+        assert_eq!(log.punches, vec![
+            Punch { device_user_id: "1".to_string(), time: at((2024, 1, 8), (8, 12, 33)) },
+            Punch { device_user_id: "12".to_string(), time: at((2024, 1, 8), (16, 20, 1)) },
+        ]);
+        assert!(log.invalid_lines.is_empty());
+    }
+
+    #[test]
+    fn test_parse_punch_log_csv_export() {
+        let log = parse_punch_log("User ID,Date,Time,State\n5,08/01/2024,08:45,C/In\n5,bad,16:30,C/Out\n");
+        assert_eq!(log.punches, vec![
+            Punch { device_user_id: "5".to_string(), time: at((2024, 1, 8), (8, 45, 0)) },
+        ]);
+        assert_eq!(log.invalid_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_late_minutes() {
+        let start = NaiveTime::from_hms_opt(8, 30, 0).unwrap();
+        assert_eq!(late_minutes(NaiveTime::from_hms_opt(8, 40, 0).unwrap(), start, 15), 0);
+        assert_eq!(late_minutes(NaiveTime::from_hms_opt(8, 50, 0).unwrap(), start, 15), 20);
+        assert_eq!(late_minutes(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), start, 15), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use chrono::{Datelike, Months, NaiveDate, Weekday};
+
+/// Format used for all full dates stored in the database (dd-MM-yyyy)
+pub const DATE_FORMAT: &str = "%d-%m-%Y";
+
+/// Parses a date stored in dd-MM-yyyy format
+pub fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), DATE_FORMAT).ok()
+}
+
+/// Formats a date as dd-MM-yyyy
+pub fn format_date(date: NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+/// Adds whole years to a date, moving 29 February to 28 February in non-leap years
+pub fn add_years(date: NaiveDate, years: i32) -> Option<NaiveDate> {
+    if years >= 0 {
+        date.checked_add_months(Months::new(years as u32 * 12))
+    } else {
+        date.checked_sub_months(Months::new(years.unsigned_abs() * 12))
+    }
+}
+
+/// Resolves an increment date stored as dd-MM to a date in the given year.
+/// 29 February falls back to 28 February in non-leap years.
+pub fn day_month_in_year(day_month: &str, year: i32) -> Option<NaiveDate> {
+    let (day, month) = day_month.trim().split_once('-')?;
+    let day: u32 = day.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+
+    NaiveDate::from_ymd_opt(year, month, day).or_else(|| {
+        if day == 29 && month == 2 {
+            NaiveDate::from_ymd_opt(year, 2, 28)
+        } else {
+            None
+        }
+    })
+}
+
+/// Number of completed years between two dates
+pub fn years_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    let mut years = to.year() - from.year();
+    if (to.month(), to.day()) < (from.month(), from.day()) {
+        years -= 1;
+    }
+    years
+}
+
+/// Number of completed months between two dates
+pub fn months_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    let mut months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    if to.day() < from.day() {
+        months -= 1;
+    }
+    months
+}
+
+/// Completed years, months and days between two dates
+pub fn years_months_days_between(from: NaiveDate, to: NaiveDate) -> (i32, i32, i32) {
+    let months = months_between(from, to);
+    let anniversary = from
+        .checked_add_months(Months::new(months.max(0) as u32))
+        .unwrap_or(from);
+    (months / 12, months % 12, (to - anniversary).num_days() as i32)
+}
+
+/// Number of days from `from` to `to` (inclusive) covered by at least one of
+/// the periods, counting overlapping periods once
+pub fn days_covered(periods: &[(NaiveDate, NaiveDate)], from: NaiveDate, to: NaiveDate) -> i64 {
+    let mut clipped: Vec<(NaiveDate, NaiveDate)> = periods.iter()
+        .map(|(start, end)| ((*start).max(from), (*end).min(to)))
+        .filter(|(start, end)| start <= end)
+        .collect();
+    clipped.sort();
+
+    let mut days = 0;
+    let mut covered_to: Option<NaiveDate> = None;
+    for (start, end) in clipped {
+        let start = match covered_to {
+            Some(covered) if covered >= end => continue,
+            Some(covered) if covered >= start => covered.succ_opt().unwrap_or(end),
+            _ => start,
+        };
+        days += (end - start).num_days() + 1;
+        covered_to = Some(end);
+    }
+
+    days
+}
+
+/// Whether two date ranges share at least one day. An open end (None) runs
+/// indefinitely.
+pub fn periods_overlap(
+    a_from: NaiveDate,
+    a_to: Option<NaiveDate>,
+    b_from: NaiveDate,
+    b_to: Option<NaiveDate>,
+) -> bool {
+    a_to.is_none_or(|a_to| b_from <= a_to) && b_to.is_none_or(|b_to| a_from <= b_to)
+}
+
+/// Number of weekdays (Monday to Friday) from `from` to `to` inclusive
+pub fn working_days_between(from: NaiveDate, to: NaiveDate) -> i32 {
+    from.iter_days()
+        .take_while(|day| *day <= to)
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+        .count() as i32
+}
+
+/// Groups a value into a band of the given width, e.g. 37 with width 5 -> "35-39"
+pub fn band_label(value: i32, width: i32) -> String {
+    let start = value.div_euclid(width) * width;
+    format!("{}-{}", start, start + width - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("05-03-1980"), NaiveDate::from_ymd_opt(1980, 3, 5));
+        assert_eq!(parse_date("1980-03-05"), None);
+        assert_eq!(parse_date("31-02-1980"), None);
+    }
+
+    #[test]
+    fn test_years_between() {
+        let dob = NaiveDate::from_ymd_opt(1980, 3, 5).unwrap();
+        assert_eq!(years_between(dob, NaiveDate::from_ymd_opt(2020, 3, 4).unwrap()), 39);
+        assert_eq!(years_between(dob, NaiveDate::from_ymd_opt(2020, 3, 5).unwrap()), 40);
+    }
+
+    #[test]
+    fn test_add_years_leap_day() {
+        let dob = NaiveDate::from_ymd_opt(1964, 2, 29).unwrap();
+        assert_eq!(add_years(dob, 60), NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(add_years(dob, 61), NaiveDate::from_ymd_opt(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_day_month_in_year() {
+        assert_eq!(day_month_in_year("15-07", 2024), NaiveDate::from_ymd_opt(2024, 7, 15));
+        assert_eq!(day_month_in_year("29-02", 2023), NaiveDate::from_ymd_opt(2023, 2, 28));
+        assert_eq!(day_month_in_year("31-04", 2024), None);
+        assert_eq!(day_month_in_year("1507", 2024), None);
+    }
+
+    #[test]
+    fn test_periods_overlap() {
+        let d = |day| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        assert!(periods_overlap(d(1), Some(d(10)), d(10), Some(d(20))));
+        assert!(!periods_overlap(d(1), Some(d(9)), d(10), Some(d(20))));
+        assert!(periods_overlap(d(1), None, d(10), Some(d(20))));
+        assert!(!periods_overlap(d(21), None, d(10), Some(d(20))));
+        assert!(periods_overlap(d(5), None, d(1), None));
+    }
+
+    #[test]
+    fn test_working_days_between() {
+        // Monday 1 January 2024 to Sunday 14 January 2024
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(working_days_between(monday, NaiveDate::from_ymd_opt(2024, 1, 14).unwrap()), 10);
+        assert_eq!(working_days_between(monday, monday), 1);
+        let saturday = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+        assert_eq!(working_days_between(saturday, saturday), 0);
+    }
+
+    #[test]
+    fn test_months_between() {
+        let from = NaiveDate::from_ymd_opt(2005, 3, 15).unwrap();
+        assert_eq!(months_between(from, NaiveDate::from_ymd_opt(2035, 3, 14).unwrap()), 359);
+        assert_eq!(months_between(from, NaiveDate::from_ymd_opt(2035, 3, 15).unwrap()), 360);
+        assert_eq!(months_between(from, from), 0);
+    }
+
+    #[test]
+    fn test_years_months_days_between() {
+        let from = NaiveDate::from_ymd_opt(2005, 3, 15).unwrap();
+        assert_eq!(years_months_days_between(from, NaiveDate::from_ymd_opt(2024, 5, 20).unwrap()), (19, 2, 5));
+        assert_eq!(years_months_days_between(from, NaiveDate::from_ymd_opt(2024, 3, 14).unwrap()), (18, 11, 28));
+    }
+
+    #[test]
+    fn test_days_covered() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let periods = [(date(1, 1), date(1, 10)), (date(1, 5), date(1, 12)), (date(2, 1), date(2, 3))];
+        assert_eq!(days_covered(&periods, date(1, 1), date(12, 31)), 15);
+        // Clipped to the window
+        assert_eq!(days_covered(&periods, date(1, 11), date(2, 1)), 3);
+    }
+
+    #[test]
+    fn test_band_label() {
+        assert_eq!(band_label(37, 5), "35-39");
+        assert_eq!(band_label(40, 5), "40-44");
+        assert_eq!(band_label(0, 5), "0-4");
+    }
+}
\ No newline at end of file
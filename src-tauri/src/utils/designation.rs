@@ -0,0 +1,47 @@
+/// Canonical designations in rank order, used to seed the designations table
+pub const DEFAULT_DESIGNATIONS: [&str; 10] = [
+    "District Forest Officer",
+    "Assistant District Forest Officer",
+    "Management Service Officer",
+    "Development Officer",
+    "Range Forest Officer",
+    "Beat Forest Officer",
+    "Extension Officer",
+    "Field Forest Assistant",
+    "Office Employee Service",
+    "Garden Labour",
+];
+
+/// Normalized form of a designation used to match free-text entries against
+/// the designations table, ignoring case, punctuation and common abbreviations
+pub fn designation_key(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| match word {
+            "asst" => "assistant",
+            "dist" => "district",
+            "dev" => "development",
+            "mgmt" => "management",
+            "off" => "officer",
+            other => other,
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_designation_key() {
+        assert_eq!(designation_key("Range Forest officer"), designation_key("Range Forest Officer"));
+        assert_eq!(designation_key("  extension   officer "), "extension officer");
+        assert_eq!(
+            designation_key("Asst.District Forest Officer"),
+            designation_key("Assistant District Forest Officer")
+        );
+        assert_ne!(designation_key("Beat Forest Officer"), designation_key("Range Forest Officer"));
+    }
+}
\ No newline at end of file
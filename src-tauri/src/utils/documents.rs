@@ -0,0 +1,63 @@
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use sha2::{Digest, Sha256};
+
+/// SHA-256 of a file's contents as lowercase hex. Stored documents are named
+/// by this hash, so identical files are only kept once.
+pub fn content_hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Opens a file with the application registered for its type. The path is
+/// passed straight to the opener rather than through a shell, so characters
+/// such as `&` in a file name are not interpreted.
+pub fn open_with_default_app(path: &Path) -> io::Result<()> {
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+
+    Command::new(program).arg(path).spawn().map(|_| ())
+}
+
+/// Name for a temporary copy of a stored document: its content hash, with
+/// the extension of the original file name so the right program opens it.
+/// Only letters and digits are kept from the extension.
+pub fn temp_file_name(hash: &str, original_file_name: Option<&str>) -> String {
+    let extension: String = original_file_name
+        .and_then(|name| Path::new(name).extension())
+        .map(|extension| extension.to_string_lossy().chars().filter(|c| c.is_ascii_alphanumeric()).collect())
+        .unwrap_or_default();
+
+    if extension.is_empty() {
+        hash.to_string()
+    } else {
+        format!("{}.{}", hash, extension.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(
+            content_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_temp_file_name() {
+        assert_eq!(temp_file_name("abc", Some("R&D Report.PDF")), "abc.pdf");
+        assert_eq!(temp_file_name("abc", Some("x&calc&.pdf")), "abc.pdf");
+        assert_eq!(temp_file_name("abc", Some("scan.p&df")), "abc.pdf");
+        assert_eq!(temp_file_name("abc", Some("no-extension")), "abc");
+        assert_eq!(temp_file_name("abc", None), "abc");
+    }
+}
\ No newline at end of file
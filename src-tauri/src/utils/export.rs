@@ -0,0 +1,208 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use printpdf::{BuiltinFont, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const LINE_HEIGHT: f32 = 5.0;
+const FONT_SIZE: f32 = 9.0;
+// Approximate width of an average Helvetica character at FONT_SIZE, in mm
+const CHAR_WIDTH: f32 = 1.75;
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(std::io::Error),
+    Pdf(printpdf::Error),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportError::Io(e) => write!(f, "Could not write file: {}", e),
+            ExportError::Pdf(e) => write!(f, "Could not generate PDF: {}", e),
+        }
+    }
+}
+
+impl From<std::io::Error> for ExportError {
+    fn from(e: std::io::Error) -> Self {
+        ExportError::Io(e)
+    }
+}
+
+impl From<printpdf::Error> for ExportError {
+    fn from(e: printpdf::Error) -> Self {
+        ExportError::Pdf(e)
+    }
+}
+
+/// Office heading printed at the top of every PDF report
+pub struct Letterhead {
+    pub title: String,
+    pub subtitle: String,
+    pub contact: Option<String>, // Address, phone and email on one line
+}
+
+/// A table in a PDF report. Column widths are in mm and should add up to at
+/// most 180 (A4 portrait minus margins).
+pub struct PdfTable {
+    pub heading: Option<String>,
+    pub headers: Vec<String>,
+    pub widths: Vec<f32>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Writes rows to a CSV file, quoting fields where needed
+pub fn write_csv(path: &str, headers: &[&str], rows: &[Vec<String>]) -> Result<(), ExportError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    let header_line: Vec<String> = headers.iter().map(|h| csv_field(h)).collect();
+    writeln!(writer, "{}", header_line.join(","))?;
+
+    for row in rows {
+        let line: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        writeln!(writer, "{}", line.join(","))?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes a simple A4 report with the office letterhead followed by the given tables
+pub fn write_pdf_report(
+    path: &str,
+    letterhead: &Letterhead,
+    title: &str,
+    subtitle: &str,
+    tables: &[PdfTable],
+) -> Result<(), ExportError> {
+    let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+    let font = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+
+    let mut writer = PdfWriter {
+        layer: doc.get_page(page).get_layer(layer),
+        doc: &doc,
+        font,
+        bold,
+        y: PAGE_HEIGHT - MARGIN,
+    };
+
+    writer.text(&letterhead.title, 14.0, MARGIN, true);
+    writer.advance(7.0);
+    writer.text(&letterhead.subtitle, 10.0, MARGIN, false);
+    if let Some(ref contact) = letterhead.contact {
+        writer.advance(LINE_HEIGHT);
+        writer.text(contact, FONT_SIZE, MARGIN, false);
+    }
+    writer.advance(8.0);
+    writer.text(title, 12.0, MARGIN, true);
+    writer.advance(6.0);
+    if !subtitle.is_empty() {
+        writer.text(subtitle, FONT_SIZE, MARGIN, false);
+        writer.advance(LINE_HEIGHT);
+    }
+
+    for table in tables {
+        writer.advance(LINE_HEIGHT);
+        if let Some(ref heading) = table.heading {
+            writer.ensure_space(LINE_HEIGHT * 3.0);
+            writer.text(heading, 10.0, MARGIN, true);
+            writer.advance(6.0);
+        }
+
+        if !table.headers.is_empty() {
+            writer.ensure_space(LINE_HEIGHT * 2.0);
+            writer.row(&table.headers, &table.widths, true);
+        }
+
+        for row in &table.rows {
+            if writer.ensure_space(LINE_HEIGHT) && !table.headers.is_empty() {
+                writer.row(&table.headers, &table.widths, true);
+            }
+            writer.row(row, &table.widths, false);
+        }
+    }
+
+    doc.save(&mut BufWriter::new(File::create(path)?))?;
+    Ok(())
+}
+
+struct PdfWriter<'a> {
+    doc: &'a PdfDocumentReference,
+    layer: PdfLayerReference,
+    font: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32,
+}
+
+impl PdfWriter<'_> {
+    fn text(&self, text: &str, size: f32, x: f32, bold: bool) {
+        let font = if bold { &self.bold } else { &self.font };
+        self.layer.use_text(text, size, Mm(x), Mm(self.y), font);
+    }
+
+    fn advance(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    /// Starts a new page if less than `height` mm is left, returning whether it did
+    fn ensure_space(&mut self, height: f32) -> bool {
+        if self.y - height >= MARGIN {
+            return false;
+        }
+
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+        true
+    }
+
+    fn row(&mut self, cells: &[String], widths: &[f32], bold: bool) {
+        let mut x = MARGIN;
+        for (cell, width) in cells.iter().zip(widths) {
+            self.text(&fit_to_width(cell, *width), FONT_SIZE, x, bold);
+            x += width;
+        }
+        self.advance(LINE_HEIGHT);
+    }
+}
+
+/// Truncates text so it fits within a column of the given width in mm
+fn fit_to_width(text: &str, width: f32) -> String {
+    let max_chars = ((width - 1.0) / CHAR_WIDTH).max(1.0) as usize;
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(max_chars.saturating_sub(2)).collect();
+        format!("{}..", truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("Range Forest Officer"), "Range Forest Officer");
+        assert_eq!(csv_field("Silva, A."), "\"Silva, A.\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_fit_to_width() {
+        assert_eq!(fit_to_width("S1", 18.0), "S1");
+        assert_eq!(fit_to_width("A very long designation name", 18.0), "A very ..");
+    }
+}
\ No newline at end of file
@@ -1 +1,8 @@
-pub mod nic_converter;
\ No newline at end of file
+pub mod nic_converter;
+pub mod date_utils;
+pub mod export;
+pub mod salary_scale;
+pub mod designation;
+pub mod attendance;
+pub mod documents;
+pub mod pension;
\ No newline at end of file
@@ -1,12 +1,16 @@
 use std::fmt;
+use std::str::FromStr;
+use chrono::{Datelike, Local, NaiveDate};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::utils::date_utils::{parse_date, format_date};
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum NICError {
     InvalidFormat,
     InvalidYear,
     InvalidDay,
     InvalidLength,
+    NoLegacyForm,
 }
 
 impl fmt::Display for NICError {
@@ -16,107 +20,226 @@ impl fmt::Display for NICError {
             NICError::InvalidYear => write!(f, "Invalid year in NIC"),
             NICError::InvalidDay => write!(f, "Invalid day in NIC"),
             NICError::InvalidLength => write!(f, "Invalid NIC length"),
+            NICError::NoLegacyForm => write!(f, "NIC has no old format equivalent"),
         }
     }
 }
 
-/// Converts old format NIC to new format
-/// Old format: YY DDD SSS C V/X (9 digits + letter)
-/// New format: YYYY DDD SSSS C (12 digits)
-#[allow(dead_code)]
-pub fn convert_old_to_new_nic(old_nic: &str) -> Result<String, NICError> {
-    // Remove any spaces and convert to uppercase
-    let cleaned = old_nic.replace(" ", "").to_uppercase();
+/// Earliest year of birth accepted in a NIC number
+const MIN_NIC_YEAR: i32 = 1900;
 
-    // Check if it's already new format (12 digits)
-    if cleaned.len() == 12 && cleaned.chars().all(|c| c.is_ascii_digit()) {
-        return Ok(cleaned);
+/// A NIC number held in the new 12-digit format. Parsing accepts either
+/// format and checks the year of birth, the day of birth (with the +500
+/// offset for women) and the length of the serial number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nic(String);
+
+impl Nic {
+    /// Wraps a NIC number read back from the database without checking it,
+    /// so records saved before NIC numbers were validated can still be
+    /// loaded, reported and corrected
+    pub fn from_stored(nic_number: String) -> Nic {
+        Nic(nic_number)
     }
 
-    // Check old format: 9 digits + 1 letter (V/X)
-    if cleaned.len() == 10 {
-        let (digits, letter) = cleaned.split_at(9);
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 
-        if !digits.chars().all(|c| c.is_ascii_digit()) {
-            return Err(NICError::InvalidFormat);
-        }
+    /// The 9 digits of the old form of this NIC. Only NICs for people born
+    /// before 2000 whose serial starts with a zero have one; the old format
+    /// had no room for the century or a fourth serial digit.
+    ///
+    /// Compare these digits, not `legacy`, against old NIC numbers: the
+    /// letter is not part of the new format and cannot be recovered.
+    pub fn legacy_digits(&self) -> Result<String, NICError> {
+        // Stored values are not validated on load
+        birth_details(&self.0)?;
 
-        if letter != "V" && letter != "X" {
-            return Err(NICError::InvalidFormat);
+        let (year, serial) = (&self.0[0..4], &self.0[7..11]);
+
+        if !year.starts_with("19") || !serial.starts_with('0') {
+            return Err(NICError::NoLegacyForm);
         }
 
-        return convert_9_digits_to_12(digits);
+        // YY DDD SSS C
+        Ok(format!("{}{}{}", &self.0[2..7], &serial[1..], &self.0[11..12]))
     }
 
-    // Check if it's just 9 digits without letter
-    if cleaned.len() == 9 && cleaned.chars().all(|c| c.is_ascii_digit()) {
-        return convert_9_digits_to_12(&cleaned);
+    /// The old 9 digits + V form of this NIC. The letter is always V: the
+    /// new format does not record whether the old number ended in V or X,
+    /// so an X number converts back with a V and will not match it as a
+    /// string. Use `legacy_digits` to compare.
+    pub fn legacy(&self) -> Result<String, NICError> {
+        Ok(format!("{}V", self.legacy_digits()?))
     }
+}
+
+impl FromStr for Nic {
+    type Err = NICError;
 
-    Err(NICError::InvalidLength)
+    fn from_str(value: &str) -> Result<Nic, NICError> {
+        // Remove any spaces and convert to uppercase
+        let cleaned = value.replace(' ', "").to_uppercase();
+
+        // Old format is 9 digits + V/X, the letter sometimes left off
+        let new_nic = match cleaned.strip_suffix(&['V', 'X'][..]) {
+            Some(digits) => convert_9_digits_to_12(digits)?,
+            None if cleaned.len() == 12 => cleaned,
+            None if cleaned.len() == 9 => convert_9_digits_to_12(&cleaned)?,
+            None if cleaned.len() == 10 && cleaned.ends_with(|c: char| c.is_ascii_alphabetic()) => {
+                return Err(NICError::InvalidFormat);
+            }
+            None => return Err(NICError::InvalidLength),
+        };
+
+        birth_details(&new_nic)?;
+        Ok(Nic(new_nic))
+    }
+}
+
+impl fmt::Display for Nic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Nic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Nic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Nic, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(|e| de::Error::custom(format!("{}: {}", e, value.trim())))
+    }
+}
+
+/// Converts old format NIC to new format
+/// Old format: YY DDD SSS C V/X (9 digits + letter)
+/// New format: YYYY DDD SSSS C (12 digits)
+pub fn convert_old_to_new_nic(old_nic: &str) -> Result<String, NICError> {
+    old_nic.parse::<Nic>().map(|nic| nic.0)
+}
+
+/// Converts new format NIC to old format, the inverse of
+/// `convert_old_to_new_nic`
+/// New format: YYYY DDD 0SSS C (12 digits)
+/// Old format: YY DDD SSS C V (9 digits + letter, always V; see `Nic::legacy`)
+pub fn convert_new_to_old_nic(new_nic: &str) -> Result<String, NICError> {
+    new_nic.parse::<Nic>()?.legacy()
 }
 
-#[allow(dead_code)]
 fn convert_9_digits_to_12(digits: &str) -> Result<String, NICError> {
     if digits.len() != 9 {
         return Err(NICError::InvalidLength);
     }
 
-    // Parse the components: YY DDD SSS C
-    let yy: u32 = digits[0..2].parse().map_err(|_| NICError::InvalidYear)?;
-    let ddd: u32 = digits[2..5].parse().map_err(|_| NICError::InvalidDay)?;
-    let sss: &str = &digits[5..8];
-    let c: &str = &digits[8..9];
-
-    // Validate day of year (1-366 for normal, 501-866 for female)
-    if (ddd < 1 || ddd > 366) && (ddd < 501 || ddd > 866) {
-        return Err(NICError::InvalidDay);
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(NICError::InvalidFormat);
     }
 
-    // Convert YY to YYYY
-    let yyyy = if yy <= 50 {  // Assuming cutoff year 50 for 20xx vs 19xx
-        2000 + yy
-    } else {
-        1900 + yy
-    };
+    // Old format NICs were only issued to people born in the 1900s
+    let (yy, ddd, sss, c) = (&digits[0..2], &digits[2..5], &digits[5..8], &digits[8..9]);
 
     // Format as 12-digit NIC: YYYY DDD 0SSS C
-    Ok(format!("{}{:03}0{}{}", yyyy, ddd, sss, c))
+    Ok(format!("19{}{}0{}{}", yy, ddd, sss, c))
 }
 
-/// Extracts information from NIC number (both old and new formats)
-#[allow(dead_code)]
-pub fn extract_nic_info(nic: &str) -> Result<NICInfo, NICError> {
-    let new_nic = convert_old_to_new_nic(nic)?;
-
+/// Date of birth and whether the holder is female, read from a new format NIC
+fn birth_details(new_nic: &str) -> Result<(NaiveDate, bool), NICError> {
     if new_nic.len() != 12 {
         return Err(NICError::InvalidLength);
     }
 
-    let year: u32 = new_nic[0..4].parse().map_err(|_| NICError::InvalidYear)?;
-    let day_of_year: u32 = new_nic[4..7].parse().map_err(|_| NICError::InvalidDay)?;
+    if !new_nic.chars().all(|c| c.is_ascii_digit()) {
+        return Err(NICError::InvalidFormat);
+    }
+
+    let year: i32 = new_nic[0..4].parse().map_err(|_| NICError::InvalidYear)?;
+    if !(MIN_NIC_YEAR..=Local::now().year()).contains(&year) {
+        return Err(NICError::InvalidYear);
+    }
 
-    let (actual_day, is_female) = if day_of_year > 500 {
-        (day_of_year - 500, true)
-    } else {
-        (day_of_year, false)
+    // Days 1-366 for men, 501-866 for women
+    let day_of_year: u32 = new_nic[4..7].parse().map_err(|_| NICError::InvalidDay)?;
+    let (day, is_female) = match day_of_year {
+        1..=366 => (day_of_year, false),
+        501..=866 => (day_of_year - 500, true),
+        _ => return Err(NICError::InvalidDay),
     };
 
+    let date_of_birth = nic_day_to_date(year, day).ok_or(NICError::InvalidDay)?;
+    Ok((date_of_birth, is_female))
+}
+
+/// Month lengths used when counting the NIC day of birth. February always
+/// has 29 days, so 1 March is day 61 whether or not the year is a leap year.
+const NIC_MONTH_DAYS: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Calendar date for a day count (1-366, without the female offset) in the
+/// given year. Day 60 is 29 February and does not exist in common years.
+pub fn nic_day_to_date(year: i32, day_of_year: u32) -> Option<NaiveDate> {
+    let mut day = day_of_year;
+
+    for (month, days) in NIC_MONTH_DAYS.iter().enumerate() {
+        if day == 0 {
+            return None;
+        }
+        if day <= *days {
+            return NaiveDate::from_ymd_opt(year, month as u32 + 1, day);
+        }
+        day -= days;
+    }
+
+    None
+}
+
+/// Extracts information from NIC number (both old and new formats)
+pub fn extract_nic_info(nic: &str) -> Result<NICInfo, NICError> {
+    let nic: Nic = nic.parse()?;
+    let (date_of_birth, is_female) = birth_details(nic.as_str())?;
+
     let gender = if is_female { "Female" } else { "Male" };
 
     Ok(NICInfo {
-        year,
-        day_of_year: actual_day,
+        date_of_birth,
         gender: gender.to_string(),
-        new_format: new_nic,
+        new_format: nic.0,
     })
 }
 
-#[allow(dead_code)]
+/// Ways a staff record's date of birth and gender disagree with its NIC
+/// number. An empty list means the record is consistent.
+pub fn nic_mismatches(nic: &str, date_of_birth: &str, gender: &str) -> Vec<String> {
+    let info = match extract_nic_info(nic) {
+        Ok(info) => info,
+        Err(e) => return vec![format!("{}: {}", e, nic.trim())],
+    };
+
+    let mut mismatches = Vec::new();
+
+    match parse_date(date_of_birth) {
+        Some(dob) if dob != info.date_of_birth => mismatches.push(format!(
+            "NIC gives date of birth {} but the record has {}",
+            format_date(info.date_of_birth), format_date(dob)
+        )),
+        Some(_) => {}
+        None => mismatches.push(format!("Date of birth is not a valid date: {}", date_of_birth)),
+    }
+
+    if !gender.trim().eq_ignore_ascii_case(&info.gender) {
+        mismatches.push(format!("NIC gives gender {} but the record has {}", info.gender, gender));
+    }
+
+    mismatches
+}
+
 #[derive(Debug)]
 pub struct NICInfo {
-    pub year: u32,
-    pub day_of_year: u32,
+    pub date_of_birth: NaiveDate,
     pub gender: String,
     pub new_format: String,
 }
@@ -136,8 +259,8 @@ mod tests {
         // Example C (female): 916980123V -> 199169800123
         assert_eq!(convert_old_to_new_nic("916980123V").unwrap(), "199169800123");
 
-        // Example D (year 2000): 001001234V -> 200010001234
-        assert_eq!(convert_old_to_new_nic("001001234V").unwrap(), "200010001234");
+        // Example D (born 1900): 001001234V -> 190010001234
+        assert_eq!(convert_old_to_new_nic("001001234V").unwrap(), "190010001234");
     }
 
     #[test]
@@ -149,13 +272,119 @@ mod tests {
     #[test]
     fn test_extract_nic_info() {
         let info = extract_nic_info("741922757V").unwrap();
-        assert_eq!(info.year, 1974);
-        assert_eq!(info.day_of_year, 192);
+        assert_eq!(info.date_of_birth, NaiveDate::from_ymd_opt(1974, 7, 10).unwrap());
         assert_eq!(info.gender, "Male");
         assert_eq!(info.new_format, "197419202757");
 
         let info_female = extract_nic_info("916980123V").unwrap();
         assert_eq!(info_female.gender, "Female");
-        assert_eq!(info_female.day_of_year, 198); // 698 - 500
+        assert_eq!(info_female.date_of_birth, NaiveDate::from_ymd_opt(1991, 7, 16).unwrap()); // Day 698 - 500
+    }
+
+    #[test]
+    fn test_nic_day_to_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(nic_day_to_date(1974, 1), date(1974, 1, 1));
+        assert_eq!(nic_day_to_date(1974, 31), date(1974, 1, 31));
+        assert_eq!(nic_day_to_date(1974, 32), date(1974, 2, 1));
+        assert_eq!(nic_day_to_date(1974, 192), date(1974, 7, 10));
+        assert_eq!(nic_day_to_date(1974, 366), date(1974, 12, 31));
+
+        // February counts 29 days every year
+        assert_eq!(nic_day_to_date(1988, 60), date(1988, 2, 29));
+        assert_eq!(nic_day_to_date(1987, 60), None);
+        assert_eq!(nic_day_to_date(1987, 61), date(1987, 3, 1));
+        assert_eq!(nic_day_to_date(1988, 61), date(1988, 3, 1));
+
+        assert_eq!(nic_day_to_date(1974, 0), None);
+        assert_eq!(nic_day_to_date(1974, 367), None);
+    }
+
+    #[test]
+    fn test_extract_date_of_birth() {
+        let info = extract_nic_info("741922757V").unwrap();
+        assert_eq!(info.date_of_birth, NaiveDate::from_ymd_opt(1974, 7, 10).unwrap());
+
+        // Female offset is removed before counting: 198 is 16 July
+        let info_female = extract_nic_info("199169800123").unwrap();
+        assert_eq!(info_female.date_of_birth, NaiveDate::from_ymd_opt(1991, 7, 16).unwrap());
+
+        let last_day = extract_nic_info("198486612345").unwrap();
+        assert_eq!(last_day.date_of_birth, NaiveDate::from_ymd_opt(1984, 12, 31).unwrap());
+
+        assert!(matches!(extract_nic_info("198706012345"), Err(NICError::InvalidDay)));
+    }
+
+    #[test]
+    fn test_nic_mismatches() {
+        assert!(nic_mismatches("741922757V", "10-07-1974", "Male").is_empty());
+        assert!(nic_mismatches("199169800123", "16-7-1991", "female").is_empty());
+
+        let mismatches = nic_mismatches("741922757V", "11-07-1974", "Female");
+        assert_eq!(mismatches, vec![
+            "NIC gives date of birth 10-07-1974 but the record has 11-07-1974".to_string(),
+            "NIC gives gender Male but the record has Female".to_string(),
+        ]);
+
+        assert_eq!(nic_mismatches("74192275", "10-07-1974", "Male"), vec!["Invalid NIC length: 74192275".to_string()]);
+    }
+    #[test]
+    fn test_nic_validation() {
+        assert_eq!("  197419202757 ".parse::<Nic>().unwrap().as_str(), "197419202757");
+        assert_eq!("741922757v".parse::<Nic>().unwrap().to_string(), "197419202757");
+        assert_eq!("741922757".parse::<Nic>().unwrap().to_string(), "197419202757");
+
+        // Year out of range
+        assert!(matches!("000099999999".parse::<Nic>(), Err(NICError::InvalidYear)));
+        assert!(matches!("189919212345".parse::<Nic>(), Err(NICError::InvalidYear)));
+        assert!(matches!("999919212345".parse::<Nic>(), Err(NICError::InvalidYear)));
+
+        // Day out of range, with and without the female offset
+        assert!(matches!("197400012345".parse::<Nic>(), Err(NICError::InvalidDay)));
+        assert!(matches!("197436712345".parse::<Nic>(), Err(NICError::InvalidDay)));
+        assert!(matches!("197450012345".parse::<Nic>(), Err(NICError::InvalidDay)));
+        assert!(matches!("197486712345".parse::<Nic>(), Err(NICError::InvalidDay)));
+        assert!(matches!("743672757V".parse::<Nic>(), Err(NICError::InvalidDay)));
+        assert!("197486612345".parse::<Nic>().is_ok());
+
+        // Serial too short or too long
+        assert!(matches!("19741921234".parse::<Nic>(), Err(NICError::InvalidLength)));
+        assert!(matches!("1974192123456".parse::<Nic>(), Err(NICError::InvalidLength)));
+        assert!(matches!("74192275V".parse::<Nic>(), Err(NICError::InvalidLength)));
+
+        assert!(matches!("74192275AV".parse::<Nic>(), Err(NICError::InvalidFormat)));
+        assert!(matches!("741922757Z".parse::<Nic>(), Err(NICError::InvalidFormat)));
+        assert!(matches!("19741920275A".parse::<Nic>(), Err(NICError::InvalidFormat)));
+    }
+
+    #[test]
+    fn test_nic_serde() {
+        let nic: Nic = serde_json::from_str("\"916980123V\"").unwrap();
+        assert_eq!(serde_json::to_string(&nic).unwrap(), "\"199169800123\"");
+
+        let error = serde_json::from_str::<Nic>("\"000099999999\"").unwrap_err();
+        assert!(error.to_string().starts_with("Invalid year in NIC: 000099999999"));
+    }
+    #[test]
+    fn test_new_to_old_conversion() {
+        assert_eq!(convert_new_to_old_nic("197419202757").unwrap(), "741922757V");
+        assert_eq!(convert_new_to_old_nic("199169800123").unwrap(), "916980123V");
+        assert_eq!(convert_new_to_old_nic("190010001234").unwrap(), "001001234V");
+
+        // Old format input comes back with a V, only the digits round trip
+        assert_eq!(convert_new_to_old_nic("741922757x").unwrap(), "741922757V");
+        assert_eq!("741922757X".parse::<Nic>().unwrap().legacy_digits().unwrap(), "741922757");
+
+        for old_nic in ["741922757V", "861234567V", "916980123V"] {
+            let new_nic = convert_old_to_new_nic(old_nic).unwrap();
+            assert_eq!(convert_new_to_old_nic(&new_nic).unwrap(), old_nic);
+        }
+
+        // Born in 2000 or later, or a serial that needs four digits
+        assert!(matches!(convert_new_to_old_nic("200010001234"), Err(NICError::NoLegacyForm)));
+        assert!(matches!(convert_new_to_old_nic("198612314567"), Err(NICError::NoLegacyForm)));
+
+        assert!(matches!(convert_new_to_old_nic("000099999999"), Err(NICError::InvalidYear)));
     }
 }
\ No newline at end of file
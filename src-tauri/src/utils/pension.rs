@@ -0,0 +1,81 @@
+use crate::database::models::PensionRules;
+
+/// Pension and gratuity worked out from service and last drawn salary
+#[derive(Debug, PartialEq)]
+pub struct PensionFigures {
+    pub pensionable: bool,
+    pub pension_percentage: f64,
+    pub monthly_pension: f64,
+    pub gratuity: f64,
+    pub reduced_monthly_pension: f64,
+}
+
+fn round_to_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+/// Estimates the pension for `service_months` of service ending on a basic
+/// salary of `last_salary`. Below the minimum service only a gratuity is paid.
+pub fn estimate_pension(rules: &PensionRules, service_months: i32, last_salary: f64) -> PensionFigures {
+    let service_years = service_months as f64 / 12.0;
+
+    if service_years < rules.minimum_service_years as f64 {
+        return PensionFigures {
+            pensionable: false,
+            pension_percentage: 0.0,
+            monthly_pension: 0.0,
+            gratuity: round_to_cents(last_salary * rules.short_service_gratuity_months * service_years.floor()),
+            reduced_monthly_pension: 0.0,
+        };
+    }
+
+    let extra_years = service_years - rules.minimum_service_years as f64;
+    let percentage = (rules.base_percentage + rules.percentage_per_year * extra_years).min(rules.maximum_percentage);
+    let monthly_pension = round_to_cents(last_salary * percentage / 100.0);
+
+    PensionFigures {
+        pensionable: true,
+        pension_percentage: (percentage * 100.0).round() / 100.0,
+        monthly_pension,
+        gratuity: round_to_cents(monthly_pension * rules.gratuity_months),
+        reduced_monthly_pension: round_to_cents(monthly_pension * (1.0 - rules.commutation_reduction / 100.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> PensionRules {
+        PensionRules {
+            minimum_service_years: 10,
+            base_percentage: 60.0,
+            percentage_per_year: 1.5,
+            maximum_percentage: 90.0,
+            gratuity_months: 24.0,
+            commutation_reduction: 50.0,
+            commutation_years: 10,
+            short_service_gratuity_months: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_estimate_pension() {
+        let figures = estimate_pension(&rules(), 20 * 12, 60000.0);
+        assert_eq!(figures.pension_percentage, 75.0);
+        assert_eq!(figures.monthly_pension, 45000.0);
+        assert_eq!(figures.gratuity, 1080000.0);
+        assert_eq!(figures.reduced_monthly_pension, 22500.0);
+
+        // Capped at the maximum percentage
+        assert_eq!(estimate_pension(&rules(), 35 * 12, 60000.0).pension_percentage, 90.0);
+    }
+
+    #[test]
+    fn test_estimate_pension_below_minimum_service() {
+        let figures = estimate_pension(&rules(), 9 * 12 + 11, 40000.0);
+        assert!(!figures.pensionable);
+        assert_eq!(figures.monthly_pension, 0.0);
+        assert_eq!(figures.gratuity, 180000.0);
+    }
+}
\ No newline at end of file
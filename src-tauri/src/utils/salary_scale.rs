@@ -0,0 +1,165 @@
+use std::fmt;
+
+// Salary amounts are compared to the cent
+const TOLERANCE: f64 = 0.005;
+
+#[derive(Debug, PartialEq)]
+pub enum ScaleError {
+    InvalidAmount(String),
+    InvalidSegment(String),
+    MaximumMismatch { expected: f64, found: f64 },
+    Empty,
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleError::InvalidAmount(s) => write!(f, "Invalid amount in salary scale: {}", s),
+            ScaleError::InvalidSegment(s) => write!(f, "Invalid increment step in salary scale: {}", s),
+            ScaleError::MaximumMismatch { expected, found } => write!(
+                f,
+                "Salary scale maximum {:.2} does not match the steps, which reach {:.2}",
+                found, expected
+            ),
+            ScaleError::Empty => write!(f, "Salary scale is empty"),
+        }
+    }
+}
+
+/// Position of a basic salary on a scale
+#[derive(Debug, PartialEq)]
+pub struct ScalePosition {
+    pub step: i32, // 0 is the initial salary
+    pub increment_amount: f64, // Increment for the next step, 0 at the maximum
+    pub next_salary: Option<f64>,
+}
+
+/// Parses a salary scale in circular notation, e.g.
+/// "27,910 - 10x300 - 11x350 - 10x560 - 10x660 - 46,960".
+/// Returns the initial salary and the (step count, increment) segments.
+/// The trailing maximum is optional but must match the steps when given.
+pub fn parse_scale_notation(notation: &str) -> Result<(f64, Vec<(i32, f64)>), ScaleError> {
+    let parts: Vec<String> = notation
+        .split('-')
+        .map(|part| part.replace([',', ' '], ""))
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let (initial, rest) = parts.split_first().ok_or(ScaleError::Empty)?;
+    let initial = parse_amount(initial)?;
+
+    let mut segments = Vec::new();
+    let mut maximum = None;
+    for (index, part) in rest.iter().enumerate() {
+        let lower = part.to_lowercase();
+        match lower.split_once(['x', '×']) {
+            Some((count, amount)) => {
+                let count: i32 = count.parse().map_err(|_| ScaleError::InvalidSegment(part.clone()))?;
+                let amount = parse_amount(amount)?;
+                if count <= 0 || amount <= 0.0 {
+                    return Err(ScaleError::InvalidSegment(part.clone()));
+                }
+                segments.push((count, amount));
+            }
+            None if index == rest.len() - 1 => maximum = Some(parse_amount(part)?),
+            None => return Err(ScaleError::InvalidSegment(part.clone())),
+        }
+    }
+
+    if let Some(found) = maximum {
+        let expected = scale_maximum(initial, &segments);
+        if (expected - found).abs() > TOLERANCE {
+            return Err(ScaleError::MaximumMismatch { expected, found });
+        }
+    }
+
+    Ok((initial, segments))
+}
+
+fn parse_amount(amount: &str) -> Result<f64, ScaleError> {
+    match amount.parse::<f64>() {
+        Ok(value) if value > 0.0 => Ok(value),
+        _ => Err(ScaleError::InvalidAmount(amount.to_string())),
+    }
+}
+
+/// Salary reached after taking every increment on the scale
+pub fn scale_maximum(initial: f64, segments: &[(i32, f64)]) -> f64 {
+    initial + segments.iter().map(|(count, amount)| *count as f64 * amount).sum::<f64>()
+}
+
+/// Finds the step a basic salary sits on, or None if it is not a point on the scale
+pub fn locate_on_scale(initial: f64, segments: &[(i32, f64)], basic_salary: f64) -> Option<ScalePosition> {
+    let mut salary = initial;
+    let mut step = 0;
+
+    for (count, amount) in segments {
+        for _ in 0..*count {
+            if (salary - basic_salary).abs() <= TOLERANCE {
+                return Some(ScalePosition {
+                    step,
+                    increment_amount: *amount,
+                    next_salary: Some(salary + amount),
+                });
+            }
+            salary += amount;
+            step += 1;
+        }
+    }
+
+    if (salary - basic_salary).abs() <= TOLERANCE {
+        return Some(ScalePosition {
+            step,
+            increment_amount: 0.0,
+            next_salary: None,
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTATION: &str = "27,910 - 10x300 - 11x350 - 10x560 - 10x660 - 46,960";
+
+    #[test]
+    fn test_parse_scale_notation() {
+        let (initial, segments) = parse_scale_notation(NOTATION).unwrap();
+        assert_eq!(initial, 27910.0);
+        assert_eq!(segments, vec![(10, 300.0), (11, 350.0), (10, 560.0), (10, 660.0)]);
+
+        // Maximum is optional
+        assert!(parse_scale_notation("27910-10x300").is_ok());
+    }
+
+    #[test]
+    fn test_parse_scale_notation_rejects_wrong_maximum() {
+        assert!(matches!(
+            parse_scale_notation("27910-10x300-31000"),
+            Err(ScaleError::MaximumMismatch { .. })
+        ));
+        assert!(parse_scale_notation("27910-abc-30910").is_err());
+    }
+
+    #[test]
+    fn test_locate_on_scale() {
+        let (initial, segments) = parse_scale_notation(NOTATION).unwrap();
+
+        let start = locate_on_scale(initial, &segments, 27910.0).unwrap();
+        assert_eq!(start.step, 0);
+        assert_eq!(start.increment_amount, 300.0);
+
+        // The end of the first segment earns the second segment's increment
+        let boundary = locate_on_scale(initial, &segments, 30910.0).unwrap();
+        assert_eq!(boundary.step, 10);
+        assert_eq!(boundary.increment_amount, 350.0);
+
+        let top = locate_on_scale(initial, &segments, 46960.0).unwrap();
+        assert_eq!(top.increment_amount, 0.0);
+        assert_eq!(top.next_salary, None);
+
+        assert_eq!(locate_on_scale(initial, &segments, 28000.0), None);
+    }
+}
\ No newline at end of file
@@ -6,11 +6,16 @@ import AddStaff from '@/components/staff/AddStaff';
 import SearchStaff from '@/components/staff/SearchStaff';
 import StaffList from '@/components/staff/StaffList';
 import { useStaffCount } from '@/hooks/useStaff';
+import { useLetterhead, useOffices } from '@/hooks/useOffices';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select';
 
 function App() {
   const [activeTab, setActiveTab] = useState('dashboard');
-  const { data: staffCount, isLoading: countLoading } = useStaffCount();
+  const [dashboardOfficeId, setDashboardOfficeId] = useState<number | undefined>(undefined);
+  const { data: offices = [] } = useOffices();
+  const { data: staffCount, isLoading: countLoading } = useStaffCount(dashboardOfficeId);
+  const office = useLetterhead();
 
   return (
     <Layout>
@@ -22,7 +27,7 @@ function App() {
               Forest Office Staff Manager
             </h1>
             <p className="text-gray-600 text-lg">
-              Divisional Forest Office - Vavuniya, Sri Lanka
+              {office.name}, Sri Lanka
             </p>
           </div>
 
@@ -53,6 +58,27 @@ function App() {
 
             {/* Dashboard Tab */}
             <TabsContent value="dashboard" className="space-y-6">
+              {offices.length > 1 && (
+                <div className="flex justify-end">
+                  <Select
+                    value={dashboardOfficeId ? String(dashboardOfficeId) : ''}
+                    onValueChange={(value) => setDashboardOfficeId(value ? Number(value) : undefined)}
+                  >
+                    <SelectTrigger className="w-64">
+                      <SelectValue placeholder="All offices" />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value="">All Offices</SelectItem>
+                      {offices.map(office => (
+                        <SelectItem key={office.id} value={String(office.id)}>
+                          {office.name}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+              )}
+
               <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-6">
                 {/* Total Staff Card */}
                 <Card className="stats-card">
@@ -11,13 +11,14 @@ import {
   Sun
 } from 'lucide-react';
 import { Button } from '@/components/ui/button';
-import { OFFICE_INFO } from '@/lib/constants';
+import { useLetterhead } from '@/hooks/useOffices';
 import { useStaffCount } from '@/hooks/useStaff';
 
 const Header: React.FC = () => {
   const [isDarkMode, setIsDarkMode] = useState(false);
   const [notifications] = useState(0); // Placeholder for notifications
   const { data: staffCount } = useStaffCount();
+  const office = useLetterhead();
 
   const toggleTheme = () => {
     setIsDarkMode(!isDarkMode);
@@ -65,7 +66,7 @@ const Header: React.FC = () => {
             {/* Office Info */}
             <div className="hidden md:block">
               <p className="text-sm font-medium text-gray-700">
-                {OFFICE_INFO.name}
+                {office.name}
               </p>
               <p className="text-xs text-gray-500">
                 {staffCount?.total || 0} Active Staff Members
@@ -156,7 +157,7 @@ const Header: React.FC = () => {
         {/* Mobile Office Info */}
         <div className="md:hidden mt-2 pt-2 border-t border-white/20">
           <div className="flex justify-between items-center text-xs">
-            <span className="text-gray-600">{OFFICE_INFO.name}</span>
+            <span className="text-gray-600">{office.name}</span>
             <span className="text-gray-500">
               {staffCount?.total || 0} Staff
             </span>
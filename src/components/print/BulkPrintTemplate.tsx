@@ -1,7 +1,7 @@
 import React from 'react';
 import { Staff, StaffSearch } from '@/types/staff';
 import { formatCurrency } from '@/lib/utils';
-import { OFFICE_INFO } from '@/lib/constants';
+import { useStaffLetterhead } from '@/hooks/useOffices';
 
 interface BulkPrintTemplateProps {
   staffList: Staff[];
@@ -27,6 +27,8 @@ const BulkPrintTemplate: React.FC<BulkPrintTemplateProps> = ({
     'basicSalary'
   ],
 }) => {
+  const letterhead = useStaffLetterhead(staffList);
+
   const renderFilterInfo = () => {
     if (!filters) return null;
 
@@ -166,9 +168,9 @@ const BulkPrintTemplate: React.FC<BulkPrintTemplateProps> = ({
     <div className="staff-print-bulk print-only">
       {/* Header */}
       <div className="staff-print-bulk-header">
-        <h1 className="staff-print-bulk-title">DIVISIONAL FOREST OFFICE - VAVUNIYA</h1>
+        <h1 className="staff-print-bulk-title">{letterhead.title}</h1>
         <h2>{title}</h2>
-        <p>Forest Department, Sri Lanka</p>
+        <p>{letterhead.subtitle}</p>
         {renderFilterInfo()}
       </div>
 
@@ -228,7 +230,7 @@ const BulkPrintTemplate: React.FC<BulkPrintTemplateProps> = ({
 
         <div className="staff-print-summary-stats" style={{ marginTop: '15px', fontSize: '8pt' }}>
           <span><strong>Report Generated:</strong> {new Date().toLocaleDateString('en-GB')} {new Date().toLocaleTimeString('en-GB')}</span>
-          <span><strong>Office:</strong> {OFFICE_INFO.name}</span>
+          <span><strong>Office:</strong> {letterhead.name}</span>
         </div>
       </div>
 
@@ -245,7 +247,7 @@ const BulkPrintTemplate: React.FC<BulkPrintTemplateProps> = ({
         fontSize: '8pt',
         color: '#666'
       }}>
-        Page 1 | {OFFICE_INFO.name}
+        Page 1 | {letterhead.name}
       </div>
     </div>
   );
@@ -90,7 +90,7 @@ const PrintDialog: React.FC<PrintDialogProps> = ({
     if (printOptions.templateType === 'individual') {
       // Print individual templates for each selected staff
       for (const staff of selectedStaff) {
-        const printContent = generateIndividualPrintContent(staff, printOptions);
+        const printContent = await generateIndividualPrintContent(staff, printOptions);
         await printHTML(printContent);
       }
     } else {
@@ -111,7 +111,7 @@ const PrintDialog: React.FC<PrintDialogProps> = ({
     if (printOptions.templateType === 'individual') {
       // Export individual PDFs
       for (const staff of selectedStaff) {
-        const printContent = generateIndividualPrintContent(staff, printOptions);
+        const printContent = await generateIndividualPrintContent(staff, printOptions);
         const filename = `staff-${staff.appointmentNumber}-${staff.fullName.replace(/\s+/g, '-').toLowerCase()}.pdf`;
         await exportToPDF(printContent, filename);
       }
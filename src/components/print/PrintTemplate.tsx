@@ -1,7 +1,8 @@
 import React from 'react';
 import { Staff } from '@/types/staff';
 import { formatCurrency, getYearsOfService } from '@/lib/utils';
-import { OFFICE_INFO } from '@/lib/constants';
+import { useLetterhead } from '@/hooks/useOffices';
+import { useDependents, relationshipLabel, nomineeLabel } from '@/hooks/useDependents';
 
 interface PrintTemplateProps {
   staff: Staff;
@@ -19,6 +20,8 @@ const PrintTemplate: React.FC<PrintTemplateProps> = ({
   includeSalaryDetails = true,
 }) => {
   const yearsOfService = getYearsOfService(staff.dateOfFirstAppointment);
+  const letterhead = useLetterhead(staff.officeId);
+  const { data: dependents = [] } = useDependents(staff.id);
 
   return (
     <div className="staff-print-individual print-only">
@@ -27,9 +30,9 @@ const PrintTemplate: React.FC<PrintTemplateProps> = ({
         <div className="staff-print-logo">
           <img src="/assets/images/forest-logo.png" alt="Forest Office Logo" />
         </div>
-        <h1 className="staff-print-title">DIVISIONAL FOREST OFFICE - VAVUNIYA</h1>
+        <h1 className="staff-print-title">{letterhead.title}</h1>
         <p className="staff-print-subtitle">Staff Information Record</p>
-        <p className="staff-print-office">Forest Department, Sri Lanka</p>
+        <p className="staff-print-office">{letterhead.subtitle}</p>
       </div>
 
       {/* Content */}
@@ -177,14 +180,44 @@ const PrintTemplate: React.FC<PrintTemplateProps> = ({
               </div>
             </div>
           )}
+
+          {/* Dependents */}
+          {includePersonalDetails && dependents.length > 0 && (
+            <div className="staff-print-section">
+              <h2 className="staff-print-section-title">Dependents</h2>
+
+              <table className="staff-print-dependents">
+                <thead>
+                  <tr>
+                    <th>Relationship</th>
+                    <th>Name</th>
+                    <th>Date of Birth</th>
+                    <th>NIC Number</th>
+                    <th>Nominee</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {dependents.map(dependent => (
+                    <tr key={dependent.id}>
+                      <td>{relationshipLabel(dependent)}</td>
+                      <td>{dependent.full_name}</td>
+                      <td>{dependent.date_of_birth || '-'}</td>
+                      <td>{dependent.nic_number || '-'}</td>
+                      <td>{nomineeLabel(dependent)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          )}
         </div>
       </div>
 
       {/* Footer */}
       <div className="staff-print-footer">
         <p>Generated on {new Date().toLocaleDateString('en-GB')} at {new Date().toLocaleTimeString('en-GB')}</p>
-        <p>{OFFICE_INFO.name}</p>
-        <p>{OFFICE_INFO.address}</p>
+        <p>{letterhead.name}</p>
+        {letterhead.contact && <p>{letterhead.contact}</p>}
       </div>
     </div>
   );
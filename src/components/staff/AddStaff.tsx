@@ -37,6 +37,7 @@ import {
   GENDERS,
 } from "@/lib/constants";
 import { useAddStaff } from "@/hooks/useStaff";
+import { useOffices } from "@/hooks/useOffices";
 import { useImageCrop } from "@/hooks/useImageCrop";
 import {
   calculateAge,
@@ -45,12 +46,8 @@ import {
   formatDateForInput,
   convertInputDateToDisplay,
 } from "@/lib/dateUtils";
-import {
-  convertOldToNewNIC,
-  getGenderFromNIC,
-  getAgeFromNIC,
-  getFormattedBirthDateFromNIC,
-} from "@/lib/nicConverter";
+import { convertOldToNewNIC } from "@/lib/nicConverter";
+import { TauriAPI, isTauriSuccess } from "@/lib/tauri";
 import ImageCropper from "./ImageCropper";
 const AddStaff: React.FC = () => {
   const [formData, setFormData] = useState<StaffFormData>({
@@ -71,6 +68,7 @@ const AddStaff: React.FC = () => {
     incrementAmount: 0,
   });
   const [errors, setErrors] = useState<Record<string, string>>({});
+  const { data: offices = [] } = useOffices();
   const [isDatePickerOpen, setIsDatePickerOpen] = useState<string | null>(null);
   const [selectedImage, setSelectedImage] = useState<File | null>(null);
   const addStaff = useAddStaff();
@@ -164,6 +162,19 @@ const AddStaff: React.FC = () => {
     },
     [errors]
   );
+  // Date of birth, gender and age are read from the NIC by the backend
+  const fillFromNIC = useCallback(async (nic: string) => {
+    const response = await TauriAPI.getNicDetails(nic);
+    if (!isTauriSuccess(response)) return;
+    const details = response.data;
+    setFormData((prev) => ({
+      ...prev,
+      gender: details.gender as "Male" | "Female",
+      age: details.age,
+      dateOfBirth: details.date_of_birth,
+      dateOfRetirement: calculateRetirementDate(details.date_of_birth),
+    }));
+  }, []);
   const handleInputChange = useCallback(
     (name: string, value: any) => {
       setFormData((prev) => {
@@ -171,17 +182,7 @@ const AddStaff: React.FC = () => {
         // Auto-calculate fields based on changes
         if (name === "nicNumber" && value) {
           try {
-            const convertedNIC = convertOldToNewNIC(value);
-            const gender = getGenderFromNIC(value);
-            const age = getAgeFromNIC(value);
-            const birthDate = getFormattedBirthDateFromNIC(value);
-            if (gender) newData.gender = gender as "Male" | "Female";
-            if (age > 0) newData.age = age;
-            if (birthDate) {
-              newData.dateOfBirth = birthDate;
-              newData.dateOfRetirement = calculateRetirementDate(birthDate);
-            }
-            newData.nicNumber = convertedNIC;
+            newData.nicNumber = convertOldToNewNIC(value);
           } catch (error) {
             console.error("NIC conversion error:", error);
           }
@@ -200,9 +201,12 @@ const AddStaff: React.FC = () => {
         }
         return newData;
       });
+      if (name === "nicNumber" && value) {
+        fillFromNIC(value);
+      }
       validateField(name, value);
     },
-    [validateField]
+    [validateField, fillFromNIC]
   );
   const handleImageUpload = useCallback(
     (file: File) => {
@@ -571,6 +575,26 @@ const AddStaff: React.FC = () => {
                     </SelectContent>
                   </Select>
                 </div>
+                <div className="space-y-2">
+                  <Label htmlFor="officeId">Office</Label>
+                  <Select
+                    value={formData.officeId ? String(formData.officeId) : ""}
+                    onValueChange={(value) =>
+                      handleInputChange("officeId", Number(value))
+                    }
+                  >
+                    <SelectTrigger>
+                      <SelectValue placeholder="Default office" />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {offices.map((office) => (
+                        <SelectItem key={office.id} value={String(office.id)}>
+                          {office.name}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
                 <div className="space-y-2">
                   <Label htmlFor="dateOfFirstAppointment" variant="required">
                     Date of First Appointment
@@ -43,6 +43,7 @@ import {
   GENDERS
 } from '@/lib/constants';
 import { useUpdateStaff } from '@/hooks/useStaff';
+import { useOffices } from '@/hooks/useOffices';
 import { useImageCrop } from '@/hooks/useImageCrop';
 import {
   calculateAge,
@@ -75,6 +76,7 @@ const EditStaff: React.FC<EditStaffProps> = ({ staff, onBack, onSave }) => {
     addressLine3: staff.addressLine3,
     contactNumber: staff.contactNumber,
     email: staff.email,
+    officeId: staff.officeId,
     designation: staff.designation,
     dateOfFirstAppointment: staff.dateOfFirstAppointment,
     dateOfRetirement: staff.dateOfRetirement,
@@ -91,6 +93,7 @@ const EditStaff: React.FC<EditStaffProps> = ({ staff, onBack, onSave }) => {
   const [hasChanges, setHasChanges] = useState(false);
 
   const updateStaff = useUpdateStaff();
+  const { data: offices = [] } = useOffices();
   const imageCrop = useImageCrop();
 
   // Track changes
@@ -268,6 +271,7 @@ const EditStaff: React.FC<EditStaffProps> = ({ staff, onBack, onSave }) => {
       addressLine3: staff.addressLine3,
       contactNumber: staff.contactNumber,
       email: staff.email,
+      officeId: staff.officeId,
       designation: staff.designation,
       dateOfFirstAppointment: staff.dateOfFirstAppointment,
       dateOfRetirement: staff.dateOfRetirement,
@@ -637,6 +641,25 @@ const EditStaff: React.FC<EditStaffProps> = ({ staff, onBack, onSave }) => {
                   </Select>
                 </div>
 
+                <div className="space-y-2">
+                  <Label htmlFor="officeId">Office</Label>
+                  <Select
+                    value={formData.officeId ? String(formData.officeId) : ''}
+                    onValueChange={(value) => handleInputChange('officeId', Number(value))}
+                  >
+                    <SelectTrigger>
+                      <SelectValue placeholder="Default office" />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {offices.map(office => (
+                        <SelectItem key={office.id} value={String(office.id)}>
+                          {office.name}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </div>
+
                 <div className="space-y-2">
                   <Label htmlFor="dateOfFirstAppointment" variant="required">
                     Date of First Appointment
@@ -44,6 +44,7 @@ import {
   SEARCH_FILTERS
 } from '@/lib/constants';
 import { useStaffSearch, useDeleteStaff } from '@/hooks/useStaff';
+import { useOffices } from '@/hooks/useOffices';
 import { usePrint } from '@/hooks/usePrint';
 import StaffDetails from './StaffDetails';
 import EditStaff from './EditStaff';
@@ -59,6 +60,7 @@ import {
 const SearchStaff: React.FC = () => {
   const [searchFilters, setSearchFilters] = useState<StaffSearch>({
     query: '',
+    officeId: undefined,
     designation: undefined,
     gender: undefined,
     maritalStatus: undefined,
@@ -69,6 +71,7 @@ const SearchStaff: React.FC = () => {
   });
 
   const [showAdvancedFilters, setShowAdvancedFilters] = useState(false);
+  const { data: offices = [] } = useOffices();
   const [selectedStaff, setSelectedStaff] = useState<Staff | null>(null);
   const [viewMode, setViewMode] = useState<'list' | 'details' | 'edit' | null>('list');
   const [deleteConfirm, setDeleteConfirm] = useState<Staff | null>(null);
@@ -94,6 +97,7 @@ const SearchStaff: React.FC = () => {
     debouncedFilters,
     // Only search if we have some criteria
     !!(debouncedFilters.query ||
+       debouncedFilters.officeId ||
        debouncedFilters.designation ||
        debouncedFilters.gender ||
        debouncedFilters.maritalStatus ||
@@ -121,6 +125,7 @@ const SearchStaff: React.FC = () => {
   const clearFilters = useCallback(() => {
     setSearchFilters({
       query: '',
+      officeId: undefined,
       designation: undefined,
       gender: undefined,
       maritalStatus: undefined,
@@ -284,6 +289,26 @@ const SearchStaff: React.FC = () => {
         {showAdvancedFilters && (
           <div className="filter-panel">
             <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-4 gap-4">
+              <div className="space-y-2">
+                <Label htmlFor="office">Office</Label>
+                <Select
+                  value={searchFilters.officeId ? String(searchFilters.officeId) : ''}
+                  onValueChange={(value) => handleFilterChange('officeId', value ? Number(value) : undefined)}
+                >
+                  <SelectTrigger>
+                    <SelectValue placeholder="All offices" />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="">All Offices</SelectItem>
+                    {offices.map(office => (
+                      <SelectItem key={office.id} value={String(office.id)}>
+                        {office.name}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              </div>
+
               <div className="space-y-2">
                 <Label htmlFor="designation">Designation</Label>
                 <Select
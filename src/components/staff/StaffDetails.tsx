@@ -25,7 +25,7 @@ import {
 import { Staff } from '@/types/staff';
 import { formatCurrency, getYearsOfService } from '@/lib/utils';
 import { usePrint } from '@/hooks/usePrint';
-import { OFFICE_INFO } from '@/lib/constants';
+import { useLetterhead } from '@/hooks/useOffices';
 
 interface StaffDetailsProps {
   staff: Staff;
@@ -44,14 +44,15 @@ const StaffDetails: React.FC<StaffDetailsProps> = ({
     exportToPDF,
     isLoading: printLoading
   } = usePrint();
+  const letterhead = useLetterhead(staff.officeId);
 
   const handlePrint = useCallback(async () => {
-    const printContent = generateIndividualPrintContent(staff);
+    const printContent = await generateIndividualPrintContent(staff);
     await printHTML(printContent);
   }, [staff, generateIndividualPrintContent, printHTML]);
 
   const handleExport = useCallback(async () => {
-    const printContent = generateIndividualPrintContent(staff);
+    const printContent = await generateIndividualPrintContent(staff);
     const filename = `staff-${staff.appointmentNumber}-${staff.fullName.replace(/\s+/g, '-').toLowerCase()}.pdf`;
     await exportToPDF(printContent, filename);
   }, [staff, generateIndividualPrintContent, exportToPDF]);
@@ -169,7 +170,7 @@ const StaffDetails: React.FC<StaffDetailsProps> = ({
                     {staff.designation}
                   </p>
                   <p className="text-gray-600">
-                    {OFFICE_INFO.name}
+                    {letterhead.name}
                   </p>
                 </div>
 
@@ -310,7 +311,7 @@ const StaffDetails: React.FC<StaffDetailsProps> = ({
             )}
             {renderDetailRow('Employee Status', 'Active')}
             {renderDetailRow('Department', 'Forest Department')}
-            {renderDetailRow('Office Location', 'Vavuniya, Sri Lanka')}
+            {renderDetailRow('Office Location', letterhead.address || letterhead.name)}
           </div>
         )}
       </div>
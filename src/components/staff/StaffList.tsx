@@ -229,7 +229,7 @@ const StaffList: React.FC = () => {
   }, [processedStaff, selectedStaffIds, generateBulkPrintContent, exportToPDF]);
 
   const handlePrintIndividual = useCallback(async (staff: Staff) => {
-    const printContent = generateIndividualPrintContent(staff);
+    const printContent = await generateIndividualPrintContent(staff);
     await printHTML(printContent);
   }, [generateIndividualPrintContent, printHTML]);
 
@@ -0,0 +1,29 @@
+import { useQuery } from '@tanstack/react-query';
+import { TauriAPI, TauriDependent } from '@/lib/tauri';
+
+// Relationships are stored in lower case
+export function relationshipLabel(dependent: TauriDependent): string {
+  return dependent.relationship.charAt(0).toUpperCase() + dependent.relationship.slice(1);
+}
+
+// Nominations printed next to a dependent's name
+export function nomineeLabel(dependent: TauriDependent): string {
+  return [
+    dependent.wop_nominee ? 'W&OP' : undefined,
+    dependent.gratuity_nominee ? 'Gratuity' : undefined,
+  ].filter(Boolean).join(', ') || '-';
+}
+
+// Custom hook for fetching a staff member's dependents
+export function useDependents(staffId?: number) {
+  return useQuery({
+    queryKey: ['dependents', staffId],
+    queryFn: async () => {
+      const response = await TauriAPI.getDependents(staffId!);
+      if (response.error) throw new Error(response.error);
+      return response.data!;
+    },
+    enabled: staffId !== undefined,
+    staleTime: 5 * 60 * 1000, // 5 minutes
+  });
+}
@@ -0,0 +1,81 @@
+import { useQuery } from '@tanstack/react-query';
+import { TauriAPI, TauriOffice } from '@/lib/tauri';
+import { OFFICE_INFO } from '@/lib/constants';
+import { Staff } from '@/types/staff';
+
+// Office details printed at the top and foot of staff records
+export interface OfficeLetterhead {
+  name: string;
+  title: string;
+  subtitle: string;
+  address?: string;
+  contact?: string; // Address, phone and email on one line
+}
+
+// Used until the offices have loaded, or if they cannot be
+export const FALLBACK_LETTERHEAD: OfficeLetterhead = {
+  name: OFFICE_INFO.name,
+  title: OFFICE_INFO.name.toUpperCase(),
+  subtitle: 'Forest Department, Sri Lanka',
+  address: OFFICE_INFO.address,
+  contact: [OFFICE_INFO.address, OFFICE_INFO.phone, OFFICE_INFO.email].join(' | '),
+};
+
+export function toLetterhead(office: TauriOffice): OfficeLetterhead {
+  const contact = [office.address, office.phone, office.email]
+    .filter((line): line is string => !!line && line.trim() !== '');
+
+  return {
+    name: office.name,
+    title: office.letterhead_title,
+    subtitle: office.letterhead_subtitle,
+    address: office.address,
+    contact: contact.length > 0 ? contact.join(' | ') : undefined,
+  };
+}
+
+/**
+ * Letterhead of an office, or of the default office when none is given
+ */
+export function resolveLetterhead(offices: TauriOffice[] | undefined, officeId?: number): OfficeLetterhead {
+  const office = (officeId !== undefined ? offices?.find((office) => office.id === officeId) : undefined)
+    ?? offices?.find((office) => office.is_default);
+
+  return office ? toLetterhead(office) : FALLBACK_LETTERHEAD;
+}
+
+/**
+ * Letterhead for a set of staff: their office when they all belong to the
+ * same one, otherwise the default office
+ */
+export function resolveStaffLetterhead(offices: TauriOffice[] | undefined, staffList: Staff[]): OfficeLetterhead {
+  const officeIds = new Set(staffList.map((staff) => staff.officeId));
+  const shared = officeIds.size === 1 ? [...officeIds][0] : undefined;
+
+  return resolveLetterhead(offices, shared);
+}
+
+// Custom hook for fetching offices
+export function useOffices() {
+  return useQuery({
+    queryKey: ['offices'],
+    queryFn: async () => {
+      const response = await TauriAPI.getOffices();
+      if (response.error) throw new Error(response.error);
+      return response.data!;
+    },
+    staleTime: 5 * 60 * 1000, // 5 minutes
+  });
+}
+
+// Letterhead of an office, or of the default office
+export function useLetterhead(officeId?: number): OfficeLetterhead {
+  const { data: offices } = useOffices();
+  return resolveLetterhead(offices, officeId);
+}
+
+// Letterhead shared by a set of staff
+export function useStaffLetterhead(staffList: Staff[]): OfficeLetterhead {
+  const { data: offices } = useOffices();
+  return resolveStaffLetterhead(offices, staffList);
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 import { useState, useCallback } from 'react';
 import { Staff, PrintOptions } from '@/types/staff';
-import { TauriPrint } from '@/lib/tauri';
+import { TauriAPI, TauriPrint } from '@/lib/tauri';
+import { useOffices, resolveLetterhead, resolveStaffLetterhead } from '@/hooks/useOffices';
+import { relationshipLabel, nomineeLabel } from '@/hooks/useDependents';
 
 interface PrintState {
   isLoading: boolean;
@@ -9,6 +11,7 @@ interface PrintState {
 }
 
 export function usePrint() {
+  const { data: offices } = useOffices();
   const [printState, setPrintState] = useState<PrintState>({
     isLoading: false,
     error: null,
@@ -59,13 +62,17 @@ export function usePrint() {
     }
   }, []);
 
-  const generateIndividualPrintContent = useCallback((staff: Staff, options?: Partial<PrintOptions>) => {
+  const generateIndividualPrintContent = useCallback(async (staff: Staff, options?: Partial<PrintOptions>) => {
     const {
       includePhoto = true,
       includePersonalDetails = true,
       includeEmploymentDetails = true,
       includeSalaryDetails = true,
     } = options || {};
+    const letterhead = resolveLetterhead(offices, staff.officeId);
+    const dependents = includePersonalDetails && staff.id !== undefined
+      ? (await TauriAPI.getDependents(staff.id)).data ?? []
+      : [];
 
     return `
       <!DOCTYPE html>
@@ -84,9 +91,9 @@ export function usePrint() {
           <div class="staff-print-logo">
             <img src="/assets/images/forest-logo.png" alt="Forest Office Logo" />
           </div>
-          <h1 class="staff-print-title">DIVISIONAL FOREST OFFICE - VAVUNIYA</h1>
+          <h1 class="staff-print-title">${letterhead.title}</h1>
           <p class="staff-print-subtitle">Staff Information Record</p>
-          <p class="staff-print-office">Forest Department, Sri Lanka</p>
+          <p class="staff-print-office">${letterhead.subtitle}</p>
         </div>
 
         <div class="staff-print-content">
@@ -189,23 +196,53 @@ export function usePrint() {
                 </div>
               </div>
             ` : ''}
+
+            ${dependents.length > 0 ? `
+              <div class="staff-print-section">
+                <h2 class="staff-print-section-title">Dependents</h2>
+                <table class="staff-print-dependents">
+                  <thead>
+                    <tr>
+                      <th>Relationship</th>
+                      <th>Name</th>
+                      <th>Date of Birth</th>
+                      <th>NIC Number</th>
+                      <th>Nominee</th>
+                    </tr>
+                  </thead>
+                  <tbody>
+                    ${dependents.map(dependent => `
+                      <tr>
+                        <td>${relationshipLabel(dependent)}</td>
+                        <td>${dependent.full_name}</td>
+                        <td>${dependent.date_of_birth || '-'}</td>
+                        <td>${dependent.nic_number || '-'}</td>
+                        <td>${nomineeLabel(dependent)}</td>
+                      </tr>
+                    `).join('')}
+                  </tbody>
+                </table>
+              </div>
+            ` : ''}
           </div>
         </div>
 
         <div class="staff-print-footer">
           <p>Generated on ${new Date().toLocaleDateString('en-GB')} at ${new Date().toLocaleTimeString('en-GB')}</p>
-          <p>Divisional Forest Office - Vavuniya, Sri Lanka</p>
+          <p>${letterhead.name}</p>
+          ${letterhead.contact ? `<p>${letterhead.contact}</p>` : ''}
         </div>
       </body>
       </html>
     `;
-  }, []);
+  }, [offices]);
 
   const generateBulkPrintContent = useCallback((
     staffList: Staff[],
     options?: Partial<PrintOptions & { title?: string }>
   ) => {
     const { title = 'Staff List', filters } = options || {};
+    const letterhead = resolveStaffLetterhead(offices, staffList);
 
     const filterText = filters ? Object.entries(filters)
       .filter(([, value]) => value !== undefined && value !== null && value !== '')
@@ -226,7 +263,7 @@ export function usePrint() {
       </head>
       <body class="staff-print-bulk">
         <div class="staff-print-bulk-header">
-          <h1 class="staff-print-bulk-title">DIVISIONAL FOREST OFFICE - VAVUNIYA</h1>
+          <h1 class="staff-print-bulk-title">${letterhead.title}</h1>
           <h2>${title}</h2>
           ${filterText ? `<div class="staff-print-bulk-filters">Filters Applied: ${filterText}</div>` : ''}
         </div>
@@ -267,12 +304,13 @@ export function usePrint() {
           <div class="staff-print-summary-stats">
             <span>Total Staff: ${staffList.length}</span>
             <span>Generated: ${new Date().toLocaleDateString('en-GB')} ${new Date().toLocaleTimeString('en-GB')}</span>
+            <span>Office: ${letterhead.name}</span>
           </div>
         </div>
       </body>
       </html>
     `;
-  }, []);
+  }, [offices]);
 
   return {
     ...printState,
@@ -413,6 +451,19 @@ function getIndividualPrintStyles(): string {
       color: #333;
     }
 
+    .staff-print-dependents {
+      width: 100%;
+      border-collapse: collapse;
+      font-size: 10px;
+    }
+
+    .staff-print-dependents th,
+    .staff-print-dependents td {
+      padding: 4px;
+      border: 1px solid #999;
+      text-align: left;
+    }
+
     .staff-print-footer {
       margin-top: 30px;
       padding-top: 15px;
@@ -51,12 +51,12 @@ export function useStaffSearch(search: StaffSearch, enabled: boolean = true) {
   });
 }
 
-// Custom hook for staff statistics
-export function useStaffCount() {
+// Custom hook for staff statistics, for one office or across every office
+export function useStaffCount(officeId?: number) {
   return useQuery({
-    queryKey: QUERY_KEYS.staffCount,
+    queryKey: [...QUERY_KEYS.staffCount, officeId] as const,
     queryFn: async () => {
-      const response = await TauriAPI.getStaffCount();
+      const response = await TauriAPI.getStaffCount(officeId);
       if (response.error) throw new Error(response.error);
       return response.data!;
     },
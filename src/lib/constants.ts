@@ -2,15 +2,15 @@ import { Designation, SalaryCode, MaritalStatus, Gender } from '@/types/staff';
 
 export const DESIGNATIONS: { value: Designation; label: string }[] = [
   { value: 'District Forest Officer', label: 'District Forest Officer' },
-  { value: 'Asst.District Forest Officer', label: 'Assistant District Forest Officer' },
+  { value: 'Assistant District Forest Officer', label: 'Assistant District Forest Officer' },
   { value: 'Management Service Officer', label: 'Management Service Officer' },
   { value: 'Development Officer', label: 'Development Officer' },
-  { value: 'Range Forest officer', label: 'Range Forest Officer' },
-  { value: 'Beat forest officer', label: 'Beat Forest Officer' },
-  { value: 'extension officer', label: 'Extension Officer' },
-  { value: 'field forest assistant', label: 'Field Forest Assistant' },
-  { value: 'office employee service', label: 'Office Employee Service' },
-  { value: 'garden labour', label: 'Garden Labour' },
+  { value: 'Range Forest Officer', label: 'Range Forest Officer' },
+  { value: 'Beat Forest Officer', label: 'Beat Forest Officer' },
+  { value: 'Extension Officer', label: 'Extension Officer' },
+  { value: 'Field Forest Assistant', label: 'Field Forest Assistant' },
+  { value: 'Office Employee Service', label: 'Office Employee Service' },
+  { value: 'Garden Labour', label: 'Garden Labour' },
 ];
 
 export const SALARY_CODES: { value: SalaryCode; label: string }[] = [
@@ -36,6 +36,8 @@ export const GENDERS: { value: Gender; label: string }[] = [
   { value: 'Female', label: 'Female' },
 ];
 
+// Fallback office details; offices and their letterheads are kept in the
+// database and loaded with TauriAPI.getOffices
 export const OFFICE_INFO = {
   name: 'Divisional Forest Office - Vavuniya',
   address: 'Forest Department, Vavuniya, Sri Lanka',
@@ -226,9 +228,9 @@ export const EXPORT_FORMATS = [
 export const SEARCH_FILTERS = {
   QUICK_FILTERS: [
     { label: 'All Staff', value: null },
-    { label: 'Officers', value: ['District Forest Officer', 'Asst.District Forest Officer', 'Range Forest officer'] },
-    { label: 'Assistant Staff', value: ['Beat forest officer', 'field forest assistant', 'extension officer'] },
-    { label: 'Support Staff', value: ['office employee service', 'garden labour'] },
+    { label: 'Officers', value: ['District Forest Officer', 'Assistant District Forest Officer', 'Range Forest Officer'] },
+    { label: 'Assistant Staff', value: ['Beat Forest Officer', 'Field Forest Assistant', 'Extension Officer'] },
+    { label: 'Support Staff', value: ['Office Employee Service', 'Garden Labour'] },
     { label: 'Recent Joiners', value: 'recent' },
     { label: 'Near Retirement', value: 'retirement' },
   ],
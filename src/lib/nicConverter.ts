@@ -68,8 +68,9 @@ function convert9DigitsTo12(digits: string): string {
   }
 
   // Convert YY to YYYY
-  // Assuming cutoff year 50: 00-50 = 2000-2050, 51-99 = 1951-1999
-  const yyyy = yy <= 50 ? 2000 + yy : 1900 + yy;
+  // Old format NICs were only issued to people born in the 1900s; those
+  // born from 2000 onwards have always had the new format
+  const yyyy = 1900 + yy;
 
   // Format as 12-digit NIC: YYYY DDD 0SSS C
   return `${yyyy}${ddd.toString().padStart(3, '0')}0${sss}${c}`;
@@ -91,7 +92,7 @@ export function extractNICInfo(nic: string): NICInfo {
 
     // Check if it's a valid year
     const currentYear = new Date().getFullYear();
-    if (year < 1900 || year > currentYear + 10) {
+    if (year < 1900 || year > currentYear) {
       throw new NICError('Invalid birth year');
     }
 
@@ -244,8 +245,8 @@ export const TEST_CASES = [
   },
   {
     old: '001001234V',
-    new: '200010001234',
-    description: 'Example D: Born in year 2000, day 100',
+    new: '190010001234',
+    description: 'Example D: Born in year 1900, day 100',
   },
 ];
 
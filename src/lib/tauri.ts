@@ -17,6 +17,7 @@ export interface TauriStaff {
   address_line3?: string;
   contact_number: string;
   email?: string;
+  office_id?: number;
   designation: string;
   date_of_first_appointment: string;
   date_of_retirement: string;
@@ -38,6 +39,45 @@ export interface TauriStaffSearch {
   age_min?: number;
   age_max?: number;
   nic_number?: string;
+  qualification?: string;
+  training?: string;
+  office_id?: number;
+}
+
+export interface TauriOffice {
+  id?: number;
+  code: string;
+  name: string;
+  address?: string;
+  phone?: string;
+  email?: string;
+  letterhead_title: string;
+  letterhead_subtitle: string;
+  is_default: boolean;
+  created_at?: string;
+  updated_at?: string;
+}
+
+export interface TauriNicDetails {
+  nic_number: string;
+  date_of_birth: string;
+  gender: string;
+  age: number;
+}
+
+export interface TauriDependent {
+  id?: number;
+  staff_id: number;
+  relationship: string;
+  full_name: string;
+  gender?: string;
+  date_of_birth?: string;
+  nic_number?: string;
+  wop_nominee: boolean;
+  gratuity_nominee: boolean;
+  remarks?: string;
+  created_at?: string;
+  updated_at?: string;
 }
 
 // Conversion functions between frontend and backend formats
@@ -56,6 +96,7 @@ export function staffToTauri(staff: Staff): TauriStaff {
     address_line3: staff.addressLine3,
     contact_number: staff.contactNumber,
     email: staff.email,
+    office_id: staff.officeId,
     designation: staff.designation,
     date_of_first_appointment: staff.dateOfFirstAppointment,
     date_of_retirement: staff.dateOfRetirement,
@@ -84,6 +125,7 @@ export function tauriToStaff(tauriStaff: TauriStaff): Staff {
     addressLine3: tauriStaff.address_line3,
     contactNumber: tauriStaff.contact_number,
     email: tauriStaff.email,
+    officeId: tauriStaff.office_id,
     designation: tauriStaff.designation as any,
     dateOfFirstAppointment: tauriStaff.date_of_first_appointment,
     dateOfRetirement: tauriStaff.date_of_retirement,
@@ -107,6 +149,9 @@ export function searchToTauri(search: StaffSearch): TauriStaffSearch {
     age_min: search.ageMin,
     age_max: search.ageMax,
     nic_number: search.nicNumber,
+    qualification: search.qualification,
+    training: search.training,
+    office_id: search.officeId,
   };
 }
 
@@ -196,9 +241,45 @@ export class TauriAPI {
   /**
    * Get staff count and statistics
    */
-  static async getStaffCount(): Promise<ApiResponse<StaffCount>> {
+  static async getStaffCount(officeId?: number): Promise<ApiResponse<StaffCount>> {
+    try {
+      const result = await invoke<StaffCount>('get_staff_count', { officeId });
+      return { data: result };
+    } catch (error) {
+      return { error: error as string };
+    }
+  }
+
+  /**
+   * Get offices with their letterhead details
+   */
+  static async getOffices(): Promise<ApiResponse<TauriOffice[]>> {
+    try {
+      const result = await invoke<TauriOffice[]>('get_offices');
+      return { data: result };
+    } catch (error) {
+      return { error: error as string };
+    }
+  }
+
+  /**
+   * Get the family members and nominees of a staff member
+   */
+  static async getDependents(staffId: number): Promise<ApiResponse<TauriDependent[]>> {
+    try {
+      const result = await invoke<TauriDependent[]>('get_dependents', { staffId });
+      return { data: result };
+    } catch (error) {
+      return { error: error as string };
+    }
+  }
+
+  /**
+   * Get the date of birth, gender and age encoded in a NIC number
+   */
+  static async getNicDetails(nicNumber: string): Promise<ApiResponse<TauriNicDetails>> {
     try {
-      const result = await invoke<StaffCount>('get_staff_count');
+      const result = await invoke<TauriNicDetails>('get_nic_details', { nicNumber });
       return { data: result };
     } catch (error) {
       return { error: error as string };
@@ -16,6 +16,7 @@ export interface Staff {
   email?: string;
 
   // Employment Details
+  officeId?: number; // The default office when not given
   designation: Designation;
   dateOfFirstAppointment: string; // Format: dd-MM-yyyy
   dateOfRetirement: string; // Auto-calculated
@@ -37,15 +38,15 @@ export interface Staff {
 
 export type Designation =
   | 'District Forest Officer'
-  | 'Asst.District Forest Officer'
+  | 'Assistant District Forest Officer'
   | 'Management Service Officer'
   | 'Development Officer'
-  | 'Range Forest officer'
-  | 'Beat forest officer'
-  | 'extension officer'
-  | 'field forest assistant'
-  | 'office employee service'
-  | 'garden labour';
+  | 'Range Forest Officer'
+  | 'Beat Forest Officer'
+  | 'Extension Officer'
+  | 'Field Forest Assistant'
+  | 'Office Employee Service'
+  | 'Garden Labour';
 
 export type SalaryCode = 'S1' | 'S2' | 'S3' | 'D1' | 'D2' | 'D3' | 'A1' | 'A2';
 
@@ -62,6 +63,9 @@ export interface StaffSearch {
   ageMin?: number;
   ageMax?: number;
   nicNumber?: string;
+  qualification?: string;
+  training?: string;
+  officeId?: number;
 }
 
 export interface StaffCount {
@@ -105,15 +109,15 @@ export interface PrintOptions {
 
 export const DESIGNATIONS: Designation[] = [
   'District Forest Officer',
-  'Asst.District Forest Officer',
+  'Assistant District Forest Officer',
   'Management Service Officer',
   'Development Officer',
-  'Range Forest officer',
-  'Beat forest officer',
-  'extension officer',
-  'field forest assistant',
-  'office employee service',
-  'garden labour',
+  'Range Forest Officer',
+  'Beat Forest Officer',
+  'Extension Officer',
+  'Field Forest Assistant',
+  'Office Employee Service',
+  'Garden Labour',
 ];
 
 export const SALARY_CODES: SalaryCode[] = ['S1', 'S2', 'S3', 'D1', 'D2', 'D3', 'A1', 'A2'];